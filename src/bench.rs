@@ -0,0 +1,275 @@
+use std::{collections::BTreeMap, fmt::Display, hint::black_box, path::Path, str::FromStr, time::{Duration, Instant}};
+
+use anyhow::{Context, Result};
+
+use crate::{ParseError, Parser, Puzzle, Records};
+
+/// Slowdowns smaller than this are put down to noise, however large they are relatively.
+pub const MIN_REGRESSION: Duration = Duration::from_micros(50);
+
+/// A timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+}
+
+impl FromStr for Stage {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s.trim() {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part1),
+            "part2" => Ok(Stage::Part2),
+            _ => Err(ParseError::new("invalid stage, expected parse, part1 or part2", s)),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The spread of a stage's run times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self { min: samples[0], median: samples[samples.len() / 2], max: samples[samples.len() - 1] }
+    }
+}
+
+/// How long a day took to parse its input and to solve each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    pub fn stage(&self, stage: Stage) -> &Stats {
+        match stage {
+            Stage::Parse => &self.parse,
+            Stage::Part1 => &self.part1,
+            Stage::Part2 => &self.part2,
+        }
+    }
+}
+
+/// Runs `puzzle` on `input` `iterations` times, timing parsing and each part separately. Both parts
+/// solve the input parsed in the same iteration.
+pub fn time<P: Puzzle + ?Sized>(puzzle: &P, input: &str, iterations: usize) -> Result<Timings> {
+    if iterations == 0 {
+        anyhow::bail!("need at least one iteration to time");
+    }
+
+    let mut samples = [(); 3].map(|_| Vec::with_capacity(iterations));
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(puzzle.parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(puzzle.part1(&parsed)?);
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(puzzle.part2(&parsed)?);
+        samples[2].push(start.elapsed());
+    }
+
+    let [parse, part1, part2] = samples.map(Stats::from_samples);
+    Ok(Timings { parse, part1, part2 })
+}
+
+/// One table row: the day and stage, then the min, median and max times.
+pub fn format_row(day: u8, stage: Stage, stats: &Stats) -> String {
+    format!("{day:02}  {stage:<5}  {:>10.1?}  {:>10.1?}  {:>10.1?}", stats.min, stats.median, stats.max)
+}
+
+/// The column headings for [`format_row`].
+pub fn header() -> String {
+    format!("{:<3} {:<5}  {:>10}  {:>10}  {:>10}", "day", "stage", "min", "median", "max")
+}
+
+/// Median times saved from an earlier run, to compare new runs against.
+///
+/// Stored as one `<day> <stage> <nanoseconds>` line per stage, where `#` starts a comment line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+/// A stage that got slower than its baseline allows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+    pub baseline: Duration,
+    pub median: Duration,
+}
+
+impl Regression {
+    /// How much slower the stage got, as a fraction of its baseline.
+    pub fn slowdown(&self) -> f64 {
+        self.median.as_secs_f64() / self.baseline.as_secs_f64() - 1.0
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.0}% slower than the baseline of {:.1?}", self.slowdown() * 100.0, self.baseline)
+    }
+}
+
+impl Baseline {
+    /// Reads the baseline at `path`, or an empty one if there is no file there yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::load_existing(path)
+    }
+
+    /// Reads the baseline at `path`, which must exist.
+    pub fn load_existing(path: &Path) -> Result<Self> {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("could not read baseline {}", path.display()))?
+            .parse::<Baseline>()
+            .with_context(|| format!("invalid baseline {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("could not write baseline {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    /// Records the medians of `timings` for `day`, replacing any earlier ones.
+    pub fn insert(&mut self, day: u8, timings: &Timings) {
+        for stage in Stage::ALL {
+            self.medians.insert((day, stage), timings.stage(stage).median);
+        }
+    }
+
+    /// Compares a new median with the baseline, flagging it when it is more than `threshold`, a
+    /// fraction such as `0.1` for 10%, slower and by at least [`MIN_REGRESSION`].
+    pub fn check(&self, day: u8, stage: Stage, median: Duration, threshold: f64) -> Option<Regression> {
+        let baseline = self.get(day, stage)?;
+        let regression = Regression { baseline, median };
+        let slower = median.saturating_sub(baseline) >= MIN_REGRESSION && regression.slowdown() > threshold;
+        slower.then_some(regression)
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = ParseError;
+
+    // 15 part1 2048000
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let entries = Records::lines(s)
+            .parse_with(|line| {
+                if line.starts_with('#') {
+                    return Ok(None);
+                }
+                let mut p = Parser::new(line);
+                let entry = ((p.integer::<u8>()?, p.value::<Stage>()?), Duration::from_nanos(p.integer()?));
+                p.end()?;
+                Ok(Some(entry))
+            })
+            .filter_map(Result::transpose)
+            .collect::<Result<BTreeMap<(u8, Stage), Duration>, ParseError>>()?;
+
+        Ok(Self { medians: entries })
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# Median benchmark times: day, stage and nanoseconds.")?;
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{day:02} {stage} {}", median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day06::Day06;
+
+    fn stats(micros: [u64; 3]) -> Stats {
+        let [min, median, max] = micros.map(Duration::from_micros);
+        Stats { min, median, max }
+    }
+
+    #[test]
+    fn test_time() -> Result<()> {
+        let timings = time(&Day06, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 5)?;
+        for stage in Stage::ALL {
+            let stats = timings.stage(stage);
+            assert!(stats.min <= stats.median && stats.median <= stats.max);
+        }
+        assert!(time(&Day06, "", 0).is_err());
+
+        let samples = [30, 10, 50, 20, 40].map(Duration::from_micros).to_vec();
+        assert_eq!(stats([10, 30, 50]), Stats::from_samples(samples));
+        Ok(())
+    }
+
+    #[test]
+    fn test_baseline_round_trip() -> Result<()> {
+        let mut baseline = Baseline::default();
+        let timings = Timings { parse: stats([1, 2, 3]), part1: stats([10, 20, 30]), part2: stats([100, 200, 300]) };
+        baseline.insert(6, &timings);
+
+        let text = baseline.to_string();
+        assert!(text.contains("\n06 part1 20000\n"), "{text}");
+        assert_eq!(baseline, text.parse::<Baseline>()?);
+
+        let error = "06 part1 20000\n06 part3 1".parse::<Baseline>().unwrap_err();
+        assert_eq!((Some(2), Some(4)), (error.line, error.column));
+
+        let missing = Path::new("no-such-baseline.txt");
+        assert_eq!(Baseline::default(), Baseline::load(missing)?);
+        assert!(Baseline::load_existing(missing).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_regressions() -> Result<()> {
+        let baseline = "01 parse 1000\n01 part1 1000000\n".parse::<Baseline>()?;
+        let millis = Duration::from_millis;
+
+        assert_eq!(None, baseline.check(1, Stage::Part1, Duration::from_micros(1050), 0.1));
+        assert_eq!(None, baseline.check(1, Stage::Part2, millis(5), 0.1));
+        assert_eq!(None, baseline.check(1, Stage::Parse, Duration::from_micros(40), 0.1));
+
+        let regression = baseline.check(1, Stage::Part1, millis(2), 0.1).unwrap();
+        assert_eq!("+100% slower than the baseline of 1.0ms", regression.to_string());
+        Ok(())
+    }
+}
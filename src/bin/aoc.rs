@@ -0,0 +1,240 @@
+use std::path::PathBuf;
+
+use advent_of_code_2022::{bench::{self, Baseline, Stage}, bench_day, days, run_day, InputSource};
+use anyhow::Result;
+
+const USAGE: &str = "usage: aoc (--all | --day <1-25>) [--part <1|2>] [--input <PATH|-> | --inputs <DIR>]
+           [--bench <N> [--baseline <FILE>] [--save-baseline <FILE>] [--threshold <PERCENT>]]";
+
+/// How much slower than its baseline a stage may get before it is flagged, in percent.
+const DEFAULT_THRESHOLD: u32 = 10;
+
+#[derive(Debug, Default)]
+struct Args {
+    day: Option<u8>,
+    part: Option<u8>,
+    all: bool,
+    source: InputSource,
+    /// Time each day over this many runs instead of printing its answers.
+    bench: Option<usize>,
+    baseline: Option<PathBuf>,
+    save_baseline: Option<PathBuf>,
+    threshold: Option<u32>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args> {
+    let mut parsed = Args::default();
+    // Which of --input and --inputs was given, since either may name the default source.
+    let mut source_flag: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => parsed.all = true,
+            "--input" | "--inputs" => {
+                let value = args.next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} needs a value\n{USAGE}"))?;
+                if let Some(given) = source_flag.replace(arg.clone()) {
+                    if given == arg {
+                        anyhow::bail!("{arg} may only be given once\n{USAGE}");
+                    }
+                    anyhow::bail!("only one of --input and --inputs may be given\n{USAGE}");
+                }
+                parsed.source = if arg == "--input" {
+                    InputSource::from_arg(&value)
+                } else {
+                    InputSource::Dir(PathBuf::from(value))
+                };
+            },
+            "--day" | "--part" => {
+                let value = args.next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} needs a value\n{USAGE}"))?
+                    .parse::<u8>()?;
+                let slot = if arg == "--day" { &mut parsed.day } else { &mut parsed.part };
+                if slot.replace(value).is_some() {
+                    anyhow::bail!("{arg} may only be given once\n{USAGE}");
+                }
+            },
+            "--bench" | "--threshold" => {
+                let value = args.next()
+                    .ok_or_else(|| anyhow::anyhow!("{arg} needs a value\n{USAGE}"))?;
+                let repeated = if arg == "--bench" {
+                    parsed.bench.replace(value.parse::<usize>()?).is_some()
+                } else {
+                    parsed.threshold.replace(value.parse::<u32>()?).is_some()
+                };
+                if repeated {
+                    anyhow::bail!("{arg} may only be given once\n{USAGE}");
+                }
+            },
+            "--baseline" | "--save-baseline" => {
+                let value = args.next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| anyhow::anyhow!("{arg} needs a value\n{USAGE}"))?;
+                let slot = if arg == "--baseline" { &mut parsed.baseline } else { &mut parsed.save_baseline };
+                if slot.replace(value).is_some() {
+                    anyhow::bail!("{arg} may only be given once\n{USAGE}");
+                }
+            },
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            },
+            _ => anyhow::bail!("unexpected argument: {arg}\n{USAGE}"),
+        }
+    }
+
+    if let Some(part) = parsed.part {
+        if part != 1 && part != 2 {
+            anyhow::bail!("invalid part: {part}\n{USAGE}");
+        }
+    }
+    if parsed.all == parsed.day.is_some() {
+        anyhow::bail!("{USAGE}");
+    }
+    if parsed.bench.is_none() && (parsed.baseline.is_some() || parsed.save_baseline.is_some() || parsed.threshold.is_some()) {
+        anyhow::bail!("--baseline, --save-baseline and --threshold only apply to --bench\n{USAGE}");
+    }
+    if parsed.bench == Some(0) {
+        anyhow::bail!("--bench needs at least one iteration\n{USAGE}");
+    }
+    if parsed.bench.is_some() && parsed.part.is_some() {
+        anyhow::bail!("--bench always times parsing and both parts, so --part can't be used with it\n{USAGE}");
+    }
+    if parsed.all && !matches!(parsed.source, InputSource::Dir(_)) {
+        anyhow::bail!("--all reads every day's input, use --inputs <DIR> instead of --input\n{USAGE}");
+    }
+
+    Ok(parsed)
+}
+
+/// Times each requested day and prints a row per stage, flagging stages that got slower than the
+/// baseline. Days that fail are reported and skipped, and the run fails at the end if any day
+/// failed or regressed.
+fn run_bench(args: &Args, iterations: usize) -> Result<()> {
+    let days = if args.all { days::DAYS.collect::<Vec<u8>>() } else { args.day.into_iter().collect() };
+    let baseline = args.baseline.as_deref().map(Baseline::load_existing).transpose()?;
+    let threshold = args.threshold.unwrap_or(DEFAULT_THRESHOLD);
+
+    // Days that aren't run this time keep their old times in the saved baseline.
+    let mut saved = args.save_baseline.as_deref().map(Baseline::load).transpose()?;
+
+    let mut failed = Vec::new();
+    let mut regressed = 0;
+    println!("{}", bench::header());
+    for day in days {
+        let timings = match bench_day(day, iterations, &args.source) {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("day {day:02} failed: {e:#}");
+                failed.push(day);
+                continue;
+            },
+        };
+
+        for stage in Stage::ALL {
+            let stats = timings.stage(stage);
+            let row = bench::format_row(day, stage, stats);
+            match baseline.as_ref().and_then(|b| b.check(day, stage, stats.median, f64::from(threshold) / 100.0)) {
+                Some(regression) => {
+                    println!("{row}  REGRESSED: {regression}");
+                    regressed += 1;
+                },
+                None => println!("{row}"),
+            }
+        }
+        if let Some(saved) = &mut saved {
+            saved.insert(day, &timings);
+        }
+    }
+
+    if let (Some(saved), Some(path)) = (&saved, &args.save_baseline) {
+        saved.save(path)?;
+        println!("saved baseline to {}", path.display());
+    }
+
+    let mut problems = Vec::new();
+    if !failed.is_empty() {
+        problems.push(format!("{} day(s) failed: {failed:?}", failed.len()));
+    }
+    if regressed > 0 {
+        problems.push(format!("{regressed} stage(s) regressed by more than {threshold}%"));
+    }
+    if !problems.is_empty() {
+        anyhow::bail!("{}", problems.join(", "));
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = parse_args(std::env::args().skip(1))?;
+
+    if let Some(iterations) = args.bench {
+        run_bench(&args, iterations)?;
+    } else if args.all {
+        let mut failed = Vec::new();
+        for day in days::DAYS {
+            if let Err(e) = run_day(day, args.part, &args.source) {
+                eprintln!("day {day:02} failed: {e:#}");
+                failed.push(day);
+            }
+        }
+        if !failed.is_empty() {
+            anyhow::bail!("{} day(s) failed: {failed:?}", failed.len());
+        }
+    } else if let Some(day) = args.day {
+        run_day(day, args.part, &args.source)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(ToString::to_string)
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<()> {
+        let parsed = parse_args(args("--day 7 --part 2"))?;
+        assert_eq!((Some(7), Some(2), false), (parsed.day, parsed.part, parsed.all));
+
+        let parsed = parse_args(args("--all"))?;
+        assert_eq!((None, None, true), (parsed.day, parsed.part, parsed.all));
+        assert_eq!(InputSource::default(), parsed.source);
+
+        let parsed = parse_args(args("--day 1 --input -"))?;
+        assert_eq!(InputSource::Stdin, parsed.source);
+
+        let parsed = parse_args(args("--all --inputs other"))?;
+        assert_eq!(InputSource::Dir("other".into()), parsed.source);
+
+        let parsed = parse_args(args("--all --bench 10 --baseline base.txt --save-baseline new.txt --threshold 25"))?;
+        assert_eq!((Some(10), Some(25)), (parsed.bench, parsed.threshold));
+        assert_eq!((Some("base.txt".into()), Some("new.txt".into())), (parsed.baseline, parsed.save_baseline));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_args_rejects_invalid_combinations() {
+        assert!(parse_args(args("")).is_err());
+        assert!(parse_args(args("--all --day 1")).is_err());
+        assert!(parse_args(args("--day 1 --part 3")).is_err());
+        assert!(parse_args(args("--day")).is_err());
+        assert!(parse_args(args("--all --input day01.txt")).is_err());
+        assert!(parse_args(args("--day 1 --input a.txt --inputs b")).is_err());
+        assert!(parse_args(args("--all --inputs inputs --input -")).is_err());
+        assert!(parse_args(args("--day 1 --input a.txt --input b.txt")).is_err());
+        assert!(parse_args(args("--day 1 --day 2")).is_err());
+        assert!(parse_args(args("--day 1 --part 1 --part 1")).is_err());
+        assert!(parse_args(args("--all --bench 5 --bench 10")).is_err());
+        assert!(parse_args(args("--all --bench 5 --baseline a.txt --baseline b.txt")).is_err());
+        assert!(parse_args(args("--day 1 --baseline base.txt")).is_err());
+        assert!(parse_args(args("--day 1 --bench 0")).is_err());
+        assert!(parse_args(args("--day 1 --bench 5 --part 1")).is_err());
+    }
+}
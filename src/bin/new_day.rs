@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+
+use advent_of_code_2022::days::DAYS;
+use anyhow::{Context, Result};
+
+const USAGE: &str = "usage: new_day <1-25> [--root <DIR>]";
+
+/// The solver, with a placeholder parser and both parts left to do.
+const DAY_TEMPLATE: &str = r###"use anyhow::Result;
+
+use crate::{Puzzle, Records};
+
+pub struct DayNN;
+
+impl Puzzle for DayNN {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        Ok(Records::lines(input).map(str::to_string).collect())
+    }
+
+    fn part1(&self, lines: &Vec<String>) -> Result<usize> {
+        part1(lines)
+    }
+
+    fn part2(&self, lines: &Vec<String>) -> Result<usize> {
+        part2(lines)
+    }
+}
+
+fn part1(_lines: &[String]) -> Result<usize> {
+    anyhow::bail!("day N part 1 is not solved yet")
+}
+
+fn part2(_lines: &[String]) -> Result<usize> {
+    anyhow::bail!("day N part 2 is not solved yet")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        DayNN;
+        #[ignore = "fill in the example and its answer"]
+        part1 { "dayNN" => 0 }
+        #[ignore = "fill in the example and its answer"]
+        part2 { "dayNN" => 0 }
+    }
+}
+"###;
+
+const BIN_TEMPLATE: &str = r###"use advent_of_code_2022::{run_day, InputSource};
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    let source = InputSource::from_args(std::env::args().skip(1))?;
+    run_day(N, None, &source)
+}
+"###;
+
+/// Fills in a template for `day`, where `NN` is the zero-padded day and a lone `N` the plain one.
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("NN", &format!("{day:02}"))
+        .replace("day N ", &format!("day {day} "))
+        .replace("(N,", &format!("({day},"))
+}
+
+/// Adds `pub mod dayNN;` and the `solution` match arm for `day` to the contents of
+/// `src/days/mod.rs`, keeping both in day order.
+fn register(module: &str, day: u8) -> Result<String> {
+    let declaration = format!("pub mod day{day:02};");
+    let arm = format!("        {day} => &day{day:02}::Day{day:02},");
+    if module.lines().any(|line| line.trim() == declaration) {
+        anyhow::bail!("day {day} is already declared in src/days/mod.rs");
+    }
+
+    let day_of = |line: &str, prefix: &str| -> Option<u8> {
+        line.trim().strip_prefix(prefix)?.get(..2)?.parse().ok()
+    };
+
+    let mut lines = module.lines().map(str::to_string).collect::<Vec<String>>();
+
+    let last_declaration = lines.iter().rposition(|line| day_of(line, "pub mod day").is_some_and(|d| d < day));
+    let first_declaration = lines.iter().position(|line| day_of(line, "pub mod day").is_some());
+    let at = last_declaration.map(|i| i + 1).or(first_declaration).unwrap_or(0);
+    lines.insert(at, declaration);
+
+    let fallback = lines.iter().position(|line| line.trim() == "_ => return None,")
+        .context("no `_ => return None,` arm in src/days/mod.rs")?;
+    let at = lines.iter()
+        .position(|line| {
+            let number = line.trim().split_once(" => ").and_then(|(n, _)| n.parse::<u8>().ok());
+            number.is_some_and(|n| n > day)
+        })
+        .unwrap_or(fallback);
+    lines.insert(at, arm);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes the files for a new day under `root`, refusing to touch anything if any of them exist.
+fn generate(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !DAYS.contains(&day) {
+        anyhow::bail!("day must be between {} and {}, got {day}", DAYS.start(), DAYS.end());
+    }
+
+    let files = [
+        (root.join(format!("src/days/day{day:02}.rs")), render(DAY_TEMPLATE, day)),
+        (root.join(format!("src/bin/day{day:02}.rs")), render(BIN_TEMPLATE, day)),
+        (root.join(format!("inputs/examples/day{day:02}.txt")), String::new()),
+    ];
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        anyhow::bail!("day {day} already exists: {} would be overwritten", path.display());
+    }
+
+    let module_path = root.join("src/days/mod.rs");
+    let module = std::fs::read_to_string(&module_path)
+        .with_context(|| format!("could not read {}", module_path.display()))?;
+    let module = register(&module, day)?;
+
+    for (path, contents) in &files {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, contents).with_context(|| format!("could not write {}", path.display()))?;
+    }
+    std::fs::write(&module_path, module)?;
+
+    Ok(files.into_iter().map(|(path, _)| path).chain([module_path]).collect())
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(u8, PathBuf)> {
+    let mut day = None;
+    let mut root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = args.next().map(PathBuf::from).with_context(|| format!("--root needs a value\n{USAGE}"))?,
+            value if day.is_none() => {
+                day = Some(value.parse::<u8>().with_context(|| format!("invalid day: {value}\n{USAGE}"))?);
+            },
+            other => anyhow::bail!("unexpected argument: {other}\n{USAGE}"),
+        }
+    }
+
+    Ok((day.with_context(|| USAGE.to_string())?, root))
+}
+
+fn main() -> Result<()> {
+    let (day, root) = parse_args(std::env::args().skip(1))?;
+    for path in generate(&root, day)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE: &str = "pub mod day01;\npub mod day03;\n\nuse crate::Solution;\n\npub fn solution(day: u8) -> Option<&'static dyn Solution> {\n    let solution: &'static dyn Solution = match day {\n        1 => &day01::Day01,\n        3 => &day03::Day03,\n        _ => return None,\n    };\n    Some(solution)\n}\n";
+
+    fn scratch_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("new_day-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::write(root.join("src/days/mod.rs"), MODULE).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_render() {
+        let day = render(DAY_TEMPLATE, 7);
+        assert!(day.contains("pub struct Day07;"));
+        assert!(day.contains("part1 { \"day07\" => 0 }"));
+        assert!(day.contains("\"day 7 part 1 is not solved yet\""));
+        assert!(render(BIN_TEMPLATE, 7).contains("run_day(7, None, &source)"));
+    }
+
+    #[test]
+    fn test_register_keeps_day_order() {
+        let module = register(MODULE, 2).unwrap();
+        assert!(module.starts_with("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(module.contains("        1 => &day01::Day01,\n        2 => &day02::Day02,\n        3 => &day03::Day03,\n"));
+
+        let module = register(MODULE, 25).unwrap();
+        assert!(module.contains("pub mod day03;\npub mod day25;\n"));
+        assert!(module.contains("        25 => &day25::Day25,\n        _ => return None,"));
+
+        assert!(register(MODULE, 3).is_err());
+    }
+
+    #[test]
+    fn test_generate_refuses_to_overwrite() {
+        let root = scratch_root("overwrite");
+        let written = generate(&root, 2).unwrap();
+        assert_eq!(4, written.len());
+        assert!(root.join("inputs/examples/day02.txt").exists());
+
+        let module = std::fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        let error = generate(&root, 2).unwrap_err();
+        assert!(error.to_string().contains("day 2 already exists"), "{error}");
+        assert_eq!(module, std::fs::read_to_string(root.join("src/days/mod.rs")).unwrap());
+
+        std::fs::write(root.join("src/bin/day03.rs"), "").unwrap();
+        assert!(generate(&root, 3).is_err());
+        assert!(generate(&root, 26).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_existing_days_are_refused() {
+        let root = scratch_root("existing");
+        let existing = ["src/days/day01.rs", "src/bin/day01.rs", "inputs/examples/day01.txt"];
+        for path in existing {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "solved").unwrap();
+        }
+
+        assert!(generate(&root, 1).is_err());
+        for path in existing {
+            assert_eq!("solved", std::fs::read_to_string(root.join(path)).unwrap());
+        }
+        assert_eq!(MODULE, std::fs::read_to_string(root.join("src/days/mod.rs")).unwrap());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::Result;
+
+use crate::{ParseError, Puzzle, Records};
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    /// The calories carried by each elf, most first.
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>> {
+        elf_inventory(input)
+    }
+
+    fn part1(&self, elf_inventory: &Vec<usize>) -> Result<usize> {
+        part1(elf_inventory)
+    }
+
+    fn part2(&self, elf_inventory: &Vec<usize>) -> Result<usize> {
+        part2(elf_inventory)
+    }
+}
+
+fn elf_inventory(input: &str) -> Result<Vec<usize>> {
+    let mut elf_inventory = Records::groups(input)
+        .parse_with(|elf| Records::lines(elf).parse::<usize>().sum())
+        .collect::<Result<Vec<usize>, ParseError>>()?;
+
+    elf_inventory.sort_by(|a, b| b.cmp(a));
+    Ok(elf_inventory)
+}
+
+fn part1(elf_inventory: &[usize]) -> Result<usize> {
+    elf_inventory.first().copied().ok_or(anyhow::anyhow!("no elves"))
+}
+
+fn part2(elf_inventory: &[usize]) -> Result<usize> {
+    let total_snacks: usize = elf_inventory.iter().take(3).sum();
+    Ok(total_snacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day01;
+        part1 { "day01" => 24000 }
+        part2 { "day01" => 45000 }
+    }
+}
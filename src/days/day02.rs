@@ -0,0 +1,154 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::{ParseError, Puzzle, Records};
+
+pub struct Day02;
+
+impl Puzzle for Day02 {
+    type Input = Vec<Round>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Round>> {
+        Ok(parse_rounds(input)?)
+    }
+
+    fn part1(&self, rounds: &Vec<Round>) -> Result<u64> {
+        Ok(run(rounds, strategy1))
+    }
+
+    fn part2(&self, rounds: &Vec<Round>) -> Result<u64> {
+        Ok(run(rounds, strategy2))
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Game {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "A" => Ok(Game::Rock),
+            "B" => Ok(Game::Paper),
+            "C" => Ok(Game::Scissors),
+            _ => Err(ParseError::new("invalid game", s)),
+        }
+    }
+}
+
+impl Game {
+    fn play(&self, other: &Game) -> Outcome {
+        match (self, other) {
+            (Game::Rock, Game::Paper) => Outcome::Lose,
+            (Game::Rock, Game::Scissors) => Outcome::Win,
+            (Game::Paper, Game::Rock) => Outcome::Win,
+            (Game::Paper, Game::Scissors) => Outcome::Lose,
+            (Game::Scissors, Game::Rock) => Outcome::Lose,
+            (Game::Scissors, Game::Paper) => Outcome::Win,
+            _ => Outcome::Draw,
+        }
+    }
+
+    fn winning_move(&self) -> Game {
+        match self {
+            Game::Rock => Game::Paper,
+            Game::Paper => Game::Scissors,
+            Game::Scissors => Game::Rock,
+        }
+    }
+
+    fn losing_move(&self) -> Game {
+        match self {
+            Game::Rock => Game::Scissors,
+            Game::Paper => Game::Rock,
+            Game::Scissors => Game::Paper,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Outcome {
+    Lose = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+/// The second column of the strategy guide, which each part reads differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for Strategy {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "X" => Ok(Strategy::X),
+            "Y" => Ok(Strategy::Y),
+            "Z" => Ok(Strategy::Z),
+            _ => Err(ParseError::new("invalid strategy", s)),
+        }
+    }
+}
+
+pub type Round = (Game, Strategy);
+
+fn parse_rounds(input: &str) -> Result<Vec<Round>, ParseError> {
+    Records::lines(input)
+        .parse_with(|line| {
+            let (oppenent, strategy) = line.split_once(' ').ok_or(ParseError::new("invalid round", line))?;
+            let oppenent = oppenent.parse::<Game>().map_err(|e| e.locate(line, oppenent))?;
+            let strategy = strategy.parse::<Strategy>().map_err(|e| e.locate(line, strategy))?;
+            Ok((oppenent, strategy))
+        })
+        .collect()
+}
+
+fn run<F>(rounds: &[Round], strategy: F) -> u64
+where F: Fn(Strategy, &Game) -> Game {
+    rounds
+        .iter()
+        .map(|(oppenent, response)| {
+            let response = strategy(*response, oppenent);
+            response.play(oppenent) as u64 + response as u64
+        })
+        .sum()
+}
+
+fn strategy1(strategy: Strategy, _oppenent: &Game) -> Game {
+    match strategy {
+        Strategy::X => Game::Rock,
+        Strategy::Y => Game::Paper,
+        Strategy::Z => Game::Scissors,
+    }
+}
+
+fn strategy2(strategy: Strategy, oppenent: &Game) -> Game {
+    match strategy {
+        Strategy::X => oppenent.losing_move(),
+        Strategy::Y => *oppenent,
+        Strategy::Z => oppenent.winning_move(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day02;
+        part1 { "day02" => 15 }
+        part2 { "day02" => 12 }
+    }
+}
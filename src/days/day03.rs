@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{ParseError, Puzzle, Records};
+
+pub struct Day03;
+
+impl Puzzle for Day03 {
+    /// Each rucksack as the priorities of the items in it.
+    type Input = Vec<Vec<u64>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u64>>> {
+        Ok(rucksacks(input)?)
+    }
+
+    fn part1(&self, rucksacks: &Vec<Vec<u64>>) -> Result<u64> {
+        part1(rucksacks)
+    }
+
+    fn part2(&self, rucksacks: &Vec<Vec<u64>>) -> Result<u64> {
+        part2(rucksacks)
+    }
+}
+
+fn char_to_score(c: char) -> Option<u64> {
+    match c {
+        'a'..='z' => Some((c as u64) - ('a' as u64) + 1),
+        'A'..='Z' => Some((c as u64) - ('A' as u64) + 27),
+        _ => None,
+    }
+}
+
+/// Each rucksack as the priorities of the items in it.
+fn rucksacks(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    Records::lines(input)
+        .parse_with(|line| {
+            line.char_indices()
+                .map(|(i, c)| {
+                    let item = &line[i..i + c.len_utf8()];
+                    char_to_score(c).ok_or_else(|| ParseError::new("invalid item", item).locate(line, item))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn common_item<'a, I>(groups: I) -> Result<u64>
+where I: IntoIterator<Item = &'a [u64]> {
+    groups
+        .into_iter()
+        .map(|items| items.iter().copied().collect::<HashSet<u64>>())
+        .reduce(|a, b| a.intersection(&b).copied().collect())
+        .and_then(|common| common.into_iter().next())
+        .ok_or(anyhow::anyhow!("no item in common"))
+}
+
+fn part1(rucksacks: &[Vec<u64>]) -> Result<u64> {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (a, b) = rucksack.split_at(rucksack.len() / 2);
+            common_item([a, b])
+        })
+        .sum()
+}
+
+fn part2(rucksacks: &[Vec<u64>]) -> Result<u64> {
+    rucksacks
+        // Chunk into the 3 elf groups
+        .chunks(3)
+        .map(|group| common_item(group.iter().map(Vec::as_slice)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day03;
+        part1 { "day03" => 157 }
+        part2 { "day03" => 70 }
+    }
+
+    #[test]
+    fn test_blank_lines_are_skipped() {
+        assert_eq!(2, rucksacks("\nvJrwpWtwJgWr\n\n  jqHRNqRjqzjG  \n\n").unwrap().len());
+
+        let error = rucksacks("vJrwpWtwJgWr\n\njqHR-qRjqzjG").unwrap_err();
+        assert_eq!((Some(2), Some(3), Some(5)), (error.record, error.line, error.column));
+    }
+}
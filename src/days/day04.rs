@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use anyhow::Result;
+
+use crate::{error::parse, ParseError, RangeSet, Records, Puzzle};
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Pair>> {
+        Ok(range_pairs(input)?)
+    }
+
+    fn part1(&self, range_pairs: &Vec<Pair>) -> Result<usize> {
+        part1(range_pairs)
+    }
+
+    fn part2(&self, range_pairs: &Vec<Pair>) -> Result<usize> {
+        part2(range_pairs)
+    }
+}
+
+pub type Pair = (RangeInclusive<i64>, RangeInclusive<i64>);
+
+fn parse_range(text: &str) -> Result<RangeInclusive<i64>, ParseError> {
+    let (start, end) = text.split_once("-").ok_or(ParseError::new("invalid range", text))?;
+    let start = parse::<i64>(start).map_err(|e| e.locate(text, start))?;
+    let end = parse::<i64>(end).map_err(|e| e.locate(text, end))?;
+    Ok(start..=end)
+}
+
+fn range_pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    Records::lines(input)
+        .parse_with(|line| {
+            let (a, b) = line.split_once(",").ok_or_else(|| ParseError::new("invalid pair", line))?;
+            let range1 = parse_range(a).map_err(|e| e.locate(line, a))?;
+            let range2 = parse_range(b).map_err(|e| e.locate(line, b))?;
+            Ok((range1, range2))
+        })
+        .collect()
+}
+
+fn part1(range_pairs: &[Pair]) -> Result<usize> {
+    let overlapping_ranges = range_pairs
+        .iter()
+        .filter(|(range1, range2)| {
+            RangeSet::from(range1.clone()).covers(range2) || RangeSet::from(range2.clone()).covers(range1)
+        });
+    Ok(overlapping_ranges.count())
+}
+
+fn part2(range_pairs: &[Pair]) -> Result<usize> {
+    let overlapping_ranges = range_pairs
+        .iter()
+        .filter(|(range1, range2)| RangeSet::from(range1.clone()).overlaps(range2));
+    Ok(overlapping_ranges.count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day04;
+        part1 { "day04" => 2 }
+        part2 { "day04" => 4 }
+    }
+
+    #[test]
+    fn test_blank_lines_are_skipped() {
+        assert_eq!(2, range_pairs("\n2-4,6-8\n\n  2-3,4-5  \n\n").unwrap().len());
+
+        let error = range_pairs("2-4,6-8\n\n2-3;4-5").unwrap_err();
+        assert_eq!((Some(2), Some(3)), (error.record, error.line));
+    }
+}
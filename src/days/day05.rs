@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::{error::parse_lines, ParseError, Parser, Puzzle};
+
+pub struct Day05;
+
+impl Puzzle for Day05 {
+    type Input = (Vec<Stack>, Vec<Instruction>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<(Vec<Stack>, Vec<Instruction>)> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(&self, (stacks, instructions): &(Vec<Stack>, Vec<Instruction>)) -> Result<String> {
+        part1(stacks, instructions)
+    }
+
+    fn part2(&self, (stacks, instructions): &(Vec<Stack>, Vec<Instruction>)) -> Result<String> {
+        part2(stacks, instructions)
+    }
+}
+
+pub type Stack = Vec<char>;
+
+fn parse_stacks(input: &str) -> Result<Vec<Stack>, ParseError> {
+    let stack_lines = input.lines().rev().collect::<Vec<&str>>();
+    let mut stacks = stack_lines
+        .first()
+        .ok_or(ParseError::new("missing stacks", input))?
+        .split_whitespace()
+        .map(|_| Vec::new())
+        .collect::<Vec<Stack>>();
+
+    for line in stack_lines.iter().skip(1) {
+        for (stack_idx, chars) in line.chars().collect::<Vec<char>>().chunks(4).enumerate() {
+            let item = match chars.get(1) {
+                Some(' ') | None => continue,
+                Some(item) => *item,
+            };
+
+            let stack = stacks.get_mut(stack_idx)
+                .ok_or_else(|| ParseError::new("crate outside of any stack", line).locate(input, line))?;
+            stack.push(item);
+        }
+    }
+
+    Ok(stacks)
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    quantity: usize,
+    src: usize,
+    dst: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    // move 1 from 2 to 1
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut p = Parser::new(s);
+        let ins = Instruction {
+            quantity: p.field("move", Parser::integer)?,
+            src: p.field("from", Parser::integer)?,
+            dst: p.field("to", Parser::integer)?,
+        };
+        p.end()?;
+
+        Ok(ins)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Stack>, Vec<Instruction>), ParseError> {
+    let (stacks_input, instructions_input) = input.split_once("\n\n")
+        .ok_or(ParseError::new("expected stacks and instructions separated by a blank line", input))?;
+    let stacks = parse_stacks(stacks_input)?;
+    let instructions = parse_lines(instructions_input.trim())
+        .map_err(|e| e.locate(input, instructions_input.trim()))?;
+    Ok((stacks, instructions))
+}
+
+fn stack_mut(stacks: &mut [Stack], number: usize) -> Result<&mut Stack> {
+    number.checked_sub(1)
+        .and_then(|idx| stacks.get_mut(idx))
+        .ok_or(anyhow::anyhow!("no stack {number}"))
+}
+
+fn pop(stacks: &mut [Stack], number: usize) -> Result<char> {
+    stack_mut(stacks, number)?.pop().ok_or(anyhow::anyhow!("stack {number} is empty"))
+}
+
+fn top_crates(stacks: &[Stack]) -> Result<String> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(idx, stack)| stack.last().ok_or(anyhow::anyhow!("stack {} is empty", idx + 1)))
+        .collect()
+}
+
+fn part1(stacks: &[Stack], instructions: &[Instruction]) -> Result<String> {
+    let mut stacks = stacks.to_vec();
+    for ins in instructions {
+        for _ in 0..ins.quantity {
+            let item = pop(&mut stacks, ins.src)?;
+            stack_mut(&mut stacks, ins.dst)?.push(item);
+        }
+    }
+
+    top_crates(&stacks)
+}
+
+fn part2(stacks: &[Stack], instructions: &[Instruction]) -> Result<String> {
+    let mut stacks = stacks.to_vec();
+    let mut tmp_stack = Vec::new();
+    for ins in instructions {
+        for _ in 0..ins.quantity {
+            tmp_stack.push(pop(&mut stacks, ins.src)?);
+        }
+        let dst_stack = stack_mut(&mut stacks, ins.dst)?;
+        while let Some(item) = tmp_stack.pop() {
+            dst_stack.push(item);
+        }
+    }
+
+    top_crates(&stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day05;
+        part1 { "day05" => "CMZ" }
+        part2 { "day05" => "MCD" }
+    }
+
+    #[test]
+    fn test_bad_instruction() {
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\nmove x from 1 to 1";
+        let error = parse_input(input).unwrap_err();
+
+        assert_eq!((Some(5), Some(6)), (error.line, error.column));
+        assert_eq!("x", error.text);
+    }
+}
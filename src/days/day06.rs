@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::Puzzle;
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, datastream: &String) -> Result<i32> {
+        Ok(chars_till_distinct(datastream, 4))
+    }
+
+    fn part2(&self, datastream: &String) -> Result<i32> {
+        Ok(chars_till_distinct(datastream, 14))
+    }
+}
+
+fn chars_till_distinct(input: &str, distinct: usize) -> i32 {
+    let mut buffer = vec!['\0'; distinct];
+
+    for (i, ch) in input.chars().enumerate() {
+        buffer[i % distinct] = ch;
+        if i < distinct {
+            continue;
+        }
+
+        let set: HashSet<&char> = HashSet::from_iter(buffer.iter());
+
+        if set.len() == distinct {
+            return 1 + i as i32;
+        }
+    }
+
+    -1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day06;
+        part1 {
+            "day06-1" => 7,
+            "day06-2" => 5,
+            "day06-3" => 6,
+            "day06-4" => 10,
+            "day06-5" => 11,
+        }
+        part2 {
+            "day06-1" => 19,
+            "day06-2" => 23,
+            "day06-3" => 23,
+            "day06-4" => 29,
+            "day06-5" => 26,
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use anyhow::Result;
+
+use crate::{error::parse, ParseError, Puzzle, Records};
+
+pub struct Day07;
+
+impl Puzzle for Day07 {
+    type Input = FileSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<FileSystem> {
+        Ok(filesystem(input)?)
+    }
+
+    fn part1(&self, fs: &FileSystem) -> Result<usize> {
+        part1(fs)
+    }
+
+    fn part2(&self, fs: &FileSystem) -> Result<usize> {
+        part2(fs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileSystem {
+    File(String, usize),
+    Directory(String, Vec<FileSystem>),
+}
+
+impl FileSystem {
+    fn size(&self) -> usize {
+        match self {
+            FileSystem::File(_, size) => *size,
+            FileSystem::Directory(_, children) => children.iter().map(|child| child.size()).sum(),
+        }
+    }
+}
+
+/// A line of terminal output, as far as it matters for building the tree.
+enum Output<'a> {
+    Up,
+    Down(&'a str),
+    File(&'a str, usize),
+    Listing,
+}
+
+impl<'a> Output<'a> {
+    fn parse(line: &'a str) -> Result<Self, ParseError> {
+        if line == "$ cd .." {
+            Ok(Output::Up)
+        } else if let Some(dir) = line.strip_prefix("$ cd ") {
+            Ok(Output::Down(dir.trim()))
+        } else if line == "$ ls" || line.starts_with("dir") {
+            Ok(Output::Listing)
+        } else {
+            let (size, name) = line.split_once(" ").ok_or_else(|| ParseError::new("invalid listing", line))?;
+            let size = parse::<usize>(size).map_err(|e| e.locate(line, size))?;
+            Ok(Output::File(name, size))
+        }
+    }
+}
+
+fn filesystem(input: &str) -> Result<FileSystem, ParseError> {
+    let mut files = Vec::new();
+    let mut stack: Vec<(String, Vec<FileSystem>)> = Vec::new();
+    let mut name = "/".to_string();
+    let lines = Records::lines(input).parse_with(|line| Ok((line, Output::parse(line)?)));
+    for (idx, record) in lines.enumerate() {
+        let (line, output) = record?;
+        match output {
+            Output::Up => {
+                let node = FileSystem::Directory(name, files);
+                (name, files) = stack.pop().ok_or_else(|| {
+                    ParseError::new("cannot leave the root directory", line).locate(input, line).in_record(idx + 1)
+                })?;
+                files.push(node);
+            },
+            Output::Down(dir) => {
+                stack.push((name, files));
+                name = dir.to_string();
+                files = Vec::new();
+            },
+            Output::File(name, size) => files.push(FileSystem::File(name.to_string(), size)),
+            Output::Listing => (),
+        }
+    };
+
+    let mut node = FileSystem::Directory(name, files.clone());
+    for (name, f) in stack.iter().rev() {
+        files = f.clone();
+        files.push(node);
+        node = FileSystem::Directory(name.clone(), files.clone());
+    };
+
+    Ok(FileSystem::Directory("/".to_string(), files))
+}
+
+fn walk_tree<F>(node: &FileSystem, f: &mut F)
+    where F: FnMut(&FileSystem) {
+        f(node);
+        match node {
+            FileSystem::File(_, _) => (),
+            FileSystem::Directory(_, children) => {
+                for child in children {
+                    walk_tree(child, f);
+                }
+            }
+        };
+}
+
+fn part1(fs: &FileSystem) -> Result<usize> {
+    let mut size = 0;
+    walk_tree(fs, &mut |node: &FileSystem| {
+        if node.size() >= 100000 {
+            return;
+        }
+
+        match node {
+            FileSystem::File(_, _) => (),
+            FileSystem::Directory(_, _) => size += node.size(),
+        };
+    });
+    Ok(size)
+}
+
+const FS_SIZE: usize = 70000000;
+const FS_UPDATE: usize = 30000000;
+
+fn part2(fs: &FileSystem) -> Result<usize> {
+    // Nothing needs freeing when the update already fits.
+    let space_needed = FS_UPDATE.saturating_sub(FS_SIZE.saturating_sub(fs.size()));
+
+    let mut size = usize::MAX;
+    walk_tree(fs, &mut |node: &FileSystem| {
+        if node.size() < space_needed {
+            return;
+        }
+
+        match node {
+            FileSystem::File(_, _) => (),
+            FileSystem::Directory(_, _) => {
+                if size > node.size() {
+                    size = node.size();
+                }
+            }
+        };
+    });
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day07;
+        part1 { "day07" => 95437 }
+        part2 { "day07" => 24933642 }
+    }
+
+    #[test]
+    fn test_leaving_the_root() {
+        let error = filesystem("$ cd /\n$ cd ..\n$ cd ..").unwrap_err();
+        assert_eq!((Some(3), Some(3)), (error.record, error.line));
+    }
+
+    #[test]
+    fn test_update_already_fits() -> Result<()> {
+        assert_eq!(100, part2(&filesystem("$ cd /\n$ ls\n100 a.txt")?)?);
+        assert_eq!(0, part2(&filesystem("")?)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::Result;
+
+use crate::{Grid, ParseError, Puzzle};
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Grid<u32>> {
+        Ok(parse_heights(input.trim())?)
+    }
+
+    fn part1(&self, grid: &Grid<u32>) -> Result<usize> {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<u32>) -> Result<u64> {
+        part2(grid)
+    }
+}
+
+fn parse_heights(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_with(input, |ch| ch.to_digit(10))
+}
+
+/// The four lines of trees seen from `(x, y)`, each paired with the position of `(x, y)` in it, so
+/// that looking out from the tree always means walking the line back towards its start.
+fn sight_lines(grid: &Grid<u32>, transposed: &Grid<u32>, (x, y): (usize, usize)) -> [(Vec<u32>, usize); 4] {
+    let row = grid.row(y);
+    let column = transposed.row(x);
+
+    [
+        (row.to_vec(), x),
+        (row.iter().rev().copied().collect(), grid.width() - x - 1),
+        (column.to_vec(), y),
+        (column.iter().rev().copied().collect(), grid.height() - y - 1),
+    ]
+}
+
+fn is_visible(line: &[u32], pos: usize) -> bool {
+    line[..pos].iter().all(|height| *height < line[pos])
+}
+
+fn scenic_score(line: &[u32], pos: usize) -> u64 {
+    let mut score = 0;
+
+    for height in line[..pos].iter().rev() {
+        score += 1;
+        if *height >= line[pos] {
+            break;
+        }
+    }
+
+    score
+}
+
+fn part1(grid: &Grid<u32>) -> Result<usize> {
+    let transposed = grid.transpose();
+
+    let visible = grid.positions()
+        .filter(|position| {
+            sight_lines(grid, &transposed, *position)
+                .iter()
+                .any(|(line, pos)| is_visible(line, *pos))
+        })
+        .count();
+
+    Ok(visible)
+}
+
+fn part2(grid: &Grid<u32>) -> Result<u64> {
+    let transposed = grid.transpose();
+
+    let score = grid.positions()
+        .map(|position| {
+            sight_lines(grid, &transposed, position)
+                .iter()
+                .map(|(line, pos)| scenic_score(line, *pos))
+                .product()
+        })
+        .max()
+        .unwrap_or(0);
+
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day08;
+        part1 { "day08" => 21 }
+        part2 { "day08" => 8 }
+    }
+
+    fn heights(row: &str) -> Vec<u32> {
+        row.chars().map(|ch| ch.to_digit(10).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        assert_eq!(2, scenic_score(&heights("33549"), 2));
+        assert_eq!(2, scenic_score(&heights("94533"), 2));
+        assert_eq!(2, scenic_score(&heights("35353"), 3));
+        assert_eq!(1, scenic_score(&heights("35353"), 1));
+    }
+}
@@ -0,0 +1,149 @@
+use std::{str::FromStr, fmt::Display, collections::HashSet};
+
+use anyhow::Result;
+
+use crate::{error::{parse, parse_lines}, geom::{Direction, Point}, ParseError, Puzzle};
+
+pub struct Day09;
+
+impl Puzzle for Day09 {
+    type Input = Vec<Command>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Command>> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(&self, commands: &Vec<Command>) -> Result<usize> {
+        part1(commands)
+    }
+
+    fn part2(&self, commands: &Vec<Command>) -> Result<usize> {
+        part2(commands)
+    }
+}
+
+#[derive(Debug)]
+pub struct Command {
+    direction: Direction,
+    steps: usize,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dir, steps) = s.split_once(" ").ok_or(ParseError::new("invalid command", s))?;
+
+        Ok(Self {
+            direction: dir.parse().map_err(|e: ParseError| e.locate(s, dir))?,
+            steps: parse(steps).map_err(|e| e.locate(s, steps))?,
+        })
+    }
+}
+
+/// A rope of any number of knots on an unbounded plane, remembering every position each knot has
+/// visited.
+struct Rope {
+    knots: Vec<Point>,
+    visited: Vec<HashSet<Point>>,
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let head = self.knots[0];
+        let tail = self.knots[self.knots.len() - 1];
+        writeln!(f, "Head: {:?} | Tail: {:?}", head, tail)?;
+
+        let points = self.knots.iter().chain(self.visited.iter().flatten());
+        let (min, max) = points.fold((head, head), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let point = Point::new(x, y);
+                let ch = match self.knots.iter().position(|knot| *knot == point) {
+                    Some(0) => 'H',
+                    Some(i) => (b'0' + (i % 10) as u8) as char,
+                    None if self.visited[self.knots.len() - 1].contains(&point) => '#',
+                    None => '.',
+                };
+                write!(f, "{}  ", ch)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        let start = Point::default();
+        Self {
+            knots: vec![start; length],
+            visited: vec![HashSet::from([start]); length],
+        }
+    }
+
+    fn move_head(&mut self, direction: Direction) {
+        self.knots[0] += direction.delta();
+        self.visited[0].insert(self.knots[0]);
+
+        for i in 1..self.knots.len() {
+            let lead = self.knots[i - 1];
+            let knot = &mut self.knots[i];
+
+            // Once a knot is touching the one ahead of it, so is every knot behind it.
+            if knot.chebyshev_distance(&lead) <= 1 { break; }
+
+            *knot += (lead - *knot).signum();
+            self.visited[i].insert(*knot);
+        }
+    }
+
+    fn tail_visited(&self) -> &HashSet<Point> {
+        &self.visited[self.visited.len() - 1]
+    }
+}
+
+fn simulate(commands: &[Command], knots: usize) -> Result<usize> {
+    let mut rope = Rope::new(knots);
+
+    for command in commands {
+        for _ in 0..command.steps {
+            rope.move_head(command.direction);
+        }
+    }
+
+    Ok(rope.tail_visited().len())
+}
+
+fn part1(commands: &[Command]) -> Result<usize> {
+    simulate(commands, 2)
+}
+
+fn part2(commands: &[Command]) -> Result<usize> {
+    simulate(commands, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day09;
+        part1 { "day09" => 13 }
+        part2 { "day09" => 1, "day09-2" => 36 }
+    }
+
+    #[test]
+    fn test_rope_leaves_the_first_quadrant() -> Result<()> {
+        assert_eq!(4, part1(&parse_lines("L 4\nD 1")?)?);
+        Ok(())
+    }
+}
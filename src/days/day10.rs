@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::{error::{parse, parse_lines}, ParseError, Puzzle};
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input = Vec<Command>;
+    type Part1 = i64;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Vec<Command>> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(&self, commands: &Vec<Command>) -> Result<i64> {
+        part1(commands)
+    }
+
+    fn part2(&self, commands: &Vec<Command>) -> Result<String> {
+        part2(commands)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Command {
+    Noop,
+    Add(i64),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        if s.trim() == "noop" {
+            return Ok(Command::Noop);
+        }
+        let val = s.trim().strip_prefix("addx ")
+            .ok_or(ParseError::new("invalid command", s))?;
+
+        Ok(Command::Add(parse::<i64>(val).map_err(|e| e.locate(s, val))?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Computer {
+    reg_x: i64,
+    cycle: u64,
+    output: Vec<(u64, i64)>,
+    screen: Vec<Vec<char>>,
+}
+
+impl Computer {
+    fn new() -> Self {
+        Self {
+            reg_x: 1,
+            cycle: 0,
+            output: vec![],
+            screen: vec![vec![' '; 40]; 6],
+        }
+    }
+
+    fn run(&mut self, cmd: &Command) {
+        match cmd {
+            Command::Noop => {
+                self.cycle += 1;
+                self.draw();
+                self.check_output();
+            },
+            Command::Add(val) => {
+                self.cycle += 1;
+                self.draw();
+                self.check_output();
+                self.cycle += 1;
+                self.draw();
+                self.check_output();
+                self.reg_x += val;
+            }
+        }
+    }
+
+    fn check_output(&mut self) {
+        if self.cycle == 20 || (self.cycle > 20 && (self.cycle - 20).is_multiple_of(40)) {
+            self.output.push((self.cycle, self.reg_x));
+        };
+    }
+
+    fn draw(&mut self) {
+        let pos = (self.cycle - 1) % 240;
+        let x = (pos / 40) as usize;
+        let y = (pos % 40) as usize;
+
+        if (y as i64) < (self.reg_x - 1) || (y as i64) > (self.reg_x + 1) {
+            self.screen[x][y] = '.';
+            return;
+        }
+
+        self.screen[x][y] = '#';
+    }
+
+    fn screen(&self) -> String {
+        self.screen
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+fn build_and_run_computer(commands: &[Command]) -> Computer {
+    let mut computer = Computer::new();
+    for cmd in commands {
+        computer.run(cmd);
+    }
+    computer
+}
+
+fn part1(commands: &[Command]) -> Result<i64> {
+    let computer = build_and_run_computer(commands);
+
+    let signal = computer.output
+        .iter()
+        .map(|(cycle, val)| *cycle as i64 * val)
+        .sum();
+
+    Ok(signal)
+}
+
+fn part2(commands: &[Command]) -> Result<String> {
+    let computer = build_and_run_computer(commands);
+    // println!();
+    // println!("{}", computer.screen());
+    // println!();
+
+    Ok(computer.screen())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day10;
+        part1 { "day10" => 13140 }
+        part2 {
+            "day10" => r###"
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+            "###,
+        }
+    }
+
+    #[test]
+    fn test_signal_strengths() {
+        let input = crate::example!("day10");
+        let computer = build_and_run_computer(&parse_lines(input).unwrap());
+        let expected = vec![
+            (20, 21),
+            (60, 19),
+            (100, 18),
+            (140, 21),
+            (180, 16),
+            (220, 18),
+        ];
+        assert_eq!(expected, computer.output);
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt::Debug, num::NonZeroU64};
+
+use anyhow::Result;
+
+use crate::{operator::Operator, ParseError, Parser, Records, Puzzle};
+
+pub struct Day11;
+
+impl Puzzle for Day11 {
+    type Input = Vec<Monkey>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Monkey>> {
+        Ok(parse_monkeys(input)?)
+    }
+
+    fn part1(&self, monkeys: &Vec<Monkey>) -> Result<u64> {
+        part1(monkeys)
+    }
+
+    fn part2(&self, monkeys: &Vec<Monkey>) -> Result<u64> {
+        part2(monkeys)
+    }
+}
+
+/// `new = x op y`, where an operand of `None` stands for `old`.
+#[derive(Debug, Clone, Copy)]
+struct Operation {
+    x: Option<u64>,
+    op: Operator,
+    y: Option<u64>,
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> u64 {
+        self.op.apply(self.x.unwrap_or(old), self.y.unwrap_or(old))
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    modulo: u64,
+    positive_monkey_idx: usize,
+    negative_monkey_idx: usize,
+}
+
+impl Debug for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Monkey {{")?;
+        writeln!(f, "  items:  {:?}", self.items)?;
+        writeln!(f, "  modulo:  {}", self.modulo)?;
+        writeln!(f, "  pos_idx:  {:?}", self.positive_monkey_idx)?;
+        writeln!(f, "  neg_idx:  {:?}", self.negative_monkey_idx)?;
+        writeln!(f, "}}")
+    }
+}
+
+impl Monkey {
+    /// Parses a monkey out of a troop of `monkeys`, which it has to throw to one of.
+    //
+    // Monkey 3:
+    //   Starting items: 74
+    //   Operation: new = old + 3
+    //   Test: divisible by 17
+    //     If true: throw to monkey 0
+    //     If false: throw to monkey 1
+    fn parse(s: &str, monkeys: usize) -> Result<Self, ParseError> {
+        // `old`, or a constant, which can't be 0 when dividing by it.
+        let operand = |p: &mut Parser, divisor: bool| {
+            if p.optional("old") {
+                Ok(None)
+            } else if divisor {
+                p.integer::<NonZeroU64>().map(|n| Some(n.get()))
+            } else {
+                p.integer::<u64>().map(Some)
+            }
+        };
+        let target = |p: &mut Parser| {
+            let token = { *p }.word()?;
+            let target = p.integer::<usize>()?;
+            if target >= monkeys {
+                return Err(p.error(format!("no monkey {target} to throw to"), token));
+            }
+            Ok(target)
+        };
+
+        let mut p = Parser::new(s);
+        p.field("Monkey", Parser::integer::<usize>)?;
+        p.literal(":")?;
+        let items = p.field("Starting items:", |p| p.separated(",", Parser::integer))?;
+
+        p.literal("Operation: new =")?;
+        let x = operand(&mut p, false)?;
+        let op = p.value::<Operator>()?;
+        let operation = Operation { x, op, y: operand(&mut p, op == Operator::Div)? };
+
+        let monkey = Self {
+            items,
+            operation,
+            modulo: p.field("Test: divisible by", Parser::integer::<NonZeroU64>)?.get(),
+            positive_monkey_idx: p.field("If true: throw to monkey", target)?,
+            negative_monkey_idx: p.field("If false: throw to monkey", target)?,
+        };
+        p.end()?;
+
+        Ok(monkey)
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let records = Records::groups(input);
+    let monkeys = records.clone().count();
+    records.parse_with(move |s| Monkey::parse(s, monkeys)).collect()
+}
+
+fn run_once(monkeys: &mut [Monkey], monkey_business: &mut [u64], soothed: bool) {
+    let modulus = monkeys.iter().fold(1, |acc, m| acc * m.modulo);
+    for i in 0..monkeys.len() {
+        let actions = {
+            let mut actions = Vec::new();
+            let monkey = &mut monkeys[i];
+
+            for item in &monkey.items {
+                monkey_business[i] += 1;
+                let mut worry_level = monkey.operation.apply(*item);
+                if soothed {
+                    worry_level /= 3;
+                }
+
+                if worry_level.is_multiple_of(monkey.modulo) {
+                    actions.push((monkey.positive_monkey_idx, worry_level % modulus));
+                } else {
+                    actions.push((monkey.negative_monkey_idx, worry_level % modulus));
+                }
+            }
+
+            monkey.items.clear();
+
+            actions
+        };
+
+        for (monkey_idx, worry_level) in actions {
+            monkeys[monkey_idx].items.push(worry_level);
+        }
+    };
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, soothed: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+    let mut monkey_business = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        run_once(&mut monkeys, &mut monkey_business, soothed);
+    }
+    monkey_business.sort_by(|a, b| b.cmp(a));
+
+    monkey_business.iter().take(2).product()
+}
+
+fn part1(monkeys: &[Monkey]) -> Result<u64> {
+    Ok(monkey_business(monkeys, 20, true))
+}
+
+fn part2(monkeys: &[Monkey]) -> Result<u64> {
+    Ok(monkey_business(monkeys, 10000, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day11;
+        part1 { "day11" => 10605 }
+        part2 { "day11" => 2713310158_u64 }
+    }
+
+    #[test]
+    fn test_zero_divisor() {
+        let input = crate::example!("day11").replacen("divisible by 19", "divisible by 0", 1);
+        let error = parse_monkeys(&input).unwrap_err();
+        assert_eq!((Some(2), Some(11), Some(22)), (error.record, error.line, error.column));
+        assert_eq!("0", error.text);
+
+        let input = crate::example!("day11").replacen("old * 19", "old / 0", 1);
+        let error = parse_monkeys(&input).unwrap_err();
+        assert_eq!((Some(1), Some(3), Some(26)), (error.record, error.line, error.column));
+        assert_eq!("0", error.text);
+    }
+
+    #[test]
+    fn test_unknown_target() {
+        let input = crate::example!("day11").replacen("throw to monkey 3", "throw to monkey 4", 1);
+        let error = parse_monkeys(&input).unwrap_err();
+        assert_eq!((Some(1), Some(6), Some(31)), (error.record, error.line, error.column));
+        assert_eq!(("no monkey 4 to throw to", "4"), (error.message.as_str(), error.text.as_str()));
+    }
+}
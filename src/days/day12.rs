@@ -0,0 +1,129 @@
+use std::{str::FromStr, fmt::Display};
+
+use anyhow::Result;
+use pathfinding::prelude::dijkstra;
+
+use crate::{grid::Position, Grid, ParseError, Puzzle};
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    type Input = Game;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Game> {
+        Ok(Game::from_str(input.trim())?)
+    }
+
+    fn part1(&self, game: &Game) -> Result<usize> {
+        part1(game)
+    }
+
+    fn part2(&self, game: &Game) -> Result<usize> {
+        part2(game)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    grid: Grid<char>,
+    position: Position,
+    goal: Position,
+    start: Position,
+    visited: Vec<Position>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse_with(s, |cell| matches!(cell, 'a'..='z' | 'S' | 'E').then_some(cell))?;
+        let start = grid.find(|cell| *cell == 'S').ok_or(ParseError::new("no start", ""))?;
+        let goal = grid.find(|cell| *cell == 'E').ok_or(ParseError::new("no goal", ""))?;
+
+        Ok(Self { grid, position: start, start, goal, visited: vec![start] })
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = self.grid.clone();
+
+        for pos in self.visited.iter() {
+            grid[*pos] = '.';
+        }
+
+        writeln!(f, "Position: {:?} | Goal: {:?} | Visited: {}", self.position, self.goal, self.visited.len())?;
+
+        grid[self.start] = 'S';
+        grid[self.goal] = 'E';
+        grid[self.position] = '#';
+
+        for row in grid.rows() {
+            for col in row.iter() {
+                write!(f, "{col}  ")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Game {
+    fn calculate_elevation(&self, position: &Position) -> isize {
+        match self.grid[*position] {
+            'S' => 'a' as isize,
+            'E' => 'z' as isize,
+            x => x as isize,
+        }
+    }
+
+    fn available_moves(&self, current_position: &Position) -> Vec<(Position, usize)> {
+        let current_elevation = self.calculate_elevation(current_position);
+
+        self.grid.neighbours4(*current_position)
+            .filter(|position| !self.visited.contains(position))
+            .filter(|position| self.calculate_elevation(position) - current_elevation <= 1)
+            .map(|position| (position, 1))
+            .collect()
+    }
+}
+
+fn part1(game: &Game) -> Result<usize> {
+    let result = dijkstra(&game.start, |position| game.available_moves(position), |position| position == &game.goal)
+        .ok_or(anyhow::anyhow!("no solution found"))?;
+    Ok(result.1)
+}
+
+fn part2(game: &Game) -> Result<usize> {
+    let mut starting_positions = vec![game.start];
+
+    for (position, cell) in game.grid.iter() {
+        if *cell == 'a' {
+            starting_positions.push(position);
+        }
+    }
+
+    let mut min = usize::MAX;
+    for pos in starting_positions.iter() {
+        if let Some(result) = dijkstra(pos, |position| game.available_moves(position), |position| position == &game.goal) {
+            if result.1 < min {
+                min = result.1
+            }
+        }
+    }
+
+    Ok(min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day12;
+        part1 { "day12" => 31 }
+        part2 { "day12" => 29 }
+    }
+}
@@ -0,0 +1,243 @@
+use std::{str::FromStr, fmt::Display, cmp::Ordering};
+
+use anyhow::Result;
+
+use crate::{error::parse, ParseError, Puzzle, Records};
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<(Packet, Packet)>> {
+        Ok(parse_pairs(input)?)
+    }
+
+    fn part1(&self, pairs: &Vec<(Packet, Packet)>) -> Result<usize> {
+        part1(pairs)
+    }
+
+    fn part2(&self, pairs: &Vec<(Packet, Packet)>) -> Result<usize> {
+        part2(pairs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Number(isize),
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::List(list) => {
+                write!(f, "[")?;
+                write!(f, "{}", list.iter().map(|p| format!("{p}")).collect::<Vec<String>>().join(","))?;
+                write!(f, "]")
+            }
+            Packet::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        if !s.starts_with('[') {
+            return Ok(Packet::Number(parse(s)?));
+        }
+
+        let error = |message: &str, idx: usize| {
+            let token = &s[idx..idx + 1];
+            ParseError::new(message, token).locate(s, token)
+        };
+
+        // The lists still open, each with where it starts, and where the current element starts.
+        // `closed` is set when that element is a list that has just been closed.
+        let mut open: Vec<(usize, Vec<Packet>)> = Vec::new();
+        let mut start = 0;
+        let mut closed = false;
+        let mut packet = None;
+
+        for (idx, ch) in s.char_indices() {
+            if ch != ']' && packet.is_some() {
+                return Err(error("unexpected text after the packet", idx));
+            }
+
+            match ch {
+                '[' => {
+                    if closed || start != idx {
+                        return Err(error("expected `,` before `[`", idx));
+                    }
+                    open.push((idx, Vec::new()));
+                    start = idx + 1;
+                },
+                ',' | ']' => {
+                    let text = &s[start..idx];
+                    let (list_start, mut list) = open.pop().ok_or_else(|| error("unmatched `]`", idx))?;
+                    if closed && !text.is_empty() {
+                        return Err(ParseError::new("expected `,`", text).locate(s, text));
+                    } else if !closed && !text.is_empty() {
+                        list.push(Packet::Number(parse(text).map_err(|e| e.locate(s, text))?));
+                    } else if !closed && (ch == ',' || !list.is_empty()) {
+                        return Err(error("empty element", idx));
+                    }
+
+                    if ch == ',' {
+                        open.push((list_start, list));
+                        closed = false;
+                    } else {
+                        match open.last_mut() {
+                            Some((_, parent)) => parent.push(Packet::List(list)),
+                            None => packet = Some(Packet::List(list)),
+                        }
+                        closed = true;
+                    }
+                    start = idx + 1;
+                },
+                _ => (),
+            }
+        }
+
+        if let Some(&(idx, _)) = open.last() {
+            return Err(error("unclosed `[`", idx));
+        }
+
+        packet.ok_or_else(|| ParseError::new("empty packet", s))
+    }
+}
+
+fn compare_packets(left: &Packet, right: &Packet) -> Option<bool> {
+    // println!("Compare {left} vs {right}");
+
+    match left {
+        Packet::Number(l) => {
+            match right {
+                Packet::Number(r) => {
+                    if l < r { return Some(true); }
+                    if l > r { return Some(false); }
+                    None
+                },
+                Packet::List(_) => {
+                    compare_packets(&Packet::List(vec![Packet::Number(*l)]), right)
+                },
+            }
+        },
+        Packet::List(l) => {
+            match right {
+                Packet::Number(r) => {
+                    compare_packets(left, &Packet::List(vec![Packet::Number(*r)]))
+                },
+                Packet::List(r) => {
+                    for (idx, p) in l.iter().enumerate() {
+                        if idx >= r.len() { return Some(false); };
+                        if let Some(result) = compare_packets(p, &r[idx]) {
+                            // if result { return Some(true); }
+                            return Some(result);
+                        };
+                    }
+                    if l.len() < r.len() {
+                        Some(true)
+                    } else {
+                        None
+                    }
+                },
+            }
+        },
+    }
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    Records::groups(input)
+        .parse_with(|lines| {
+            let packets = Records::lines(lines).parse::<Packet>().collect::<Result<Vec<Packet>, ParseError>>()?;
+            let [left, right] = <[Packet; 2]>::try_from(packets)
+                .map_err(|_| ParseError::new("expected a pair of packets", lines))?;
+            Ok((left, right))
+        })
+        .collect()
+}
+
+fn part1(pairs: &[(Packet, Packet)]) -> Result<usize> {
+    let mut sum = 0;
+    for (idx, pair) in pairs.iter().enumerate() {
+        let result = compare_packets(&pair.0, &pair.1)
+            .ok_or(anyhow::anyhow!("no result for {pair:?}"))?;
+        if result {
+            sum += idx + 1;
+        }
+    }
+
+    Ok(sum)
+}
+
+fn part2(pairs: &[(Packet, Packet)]) -> Result<usize> {
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect::<Vec<Packet>>();
+
+    let divider_1 = Packet::from_str("[[6]]")?;
+    let divider_2 = Packet::from_str("[[2]]")?;
+    packets.push(divider_1.clone());
+    packets.push(divider_2.clone());
+
+    packets.sort_by(|a, b| {
+        match compare_packets(a, b) {
+            Some(true) => Ordering::Less,
+            Some(false) => Ordering::Greater,
+            None => Ordering::Equal,
+        }
+    });
+
+    let mut result = 1;
+    for (idx, packet) in packets.iter().enumerate() {
+        if divider_1 == *packet  || divider_2 == *packet {
+            result *= idx + 1;
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day13;
+        part1 { "day13" => 13 }
+        part2 { "day13" => 140 }
+    }
+
+    #[test]
+    fn test_packet_round_trip() -> Result<()> {
+        for packet in ["[]", "[[]]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[4,4],4,4]", "[10,[],-1]"] {
+            assert_eq!(packet, packet.parse::<Packet>()?.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_packets() {
+        for (packet, message, column) in [
+            ("[1", "unclosed `[`", 1),
+            ("[[1]", "unclosed `[`", 1),
+            ("[1,[2]", "unclosed `[`", 1),
+            ("[1]]", "unmatched `]`", 4),
+            ("[1][2]", "unexpected text after the packet", 4),
+            ("[,]", "empty element", 2),
+            ("[1,,2]", "empty element", 4),
+            ("[1,]", "empty element", 4),
+            ("[[1]2]", "expected `,`", 5),
+            ("[1[2]]", "expected `,` before `[`", 3),
+            ("[1,x]", "invalid digit found in string", 4),
+        ] {
+            let error = packet.parse::<Packet>().unwrap_err();
+            assert_eq!((message, Some(column)), (error.message.as_str(), error.column), "{packet}");
+        }
+    }
+}
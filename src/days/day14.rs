@@ -0,0 +1,189 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::{geom::Point, grid::Position, Grid, ParseError, Puzzle, Records};
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input = Vec<Line>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Line>> {
+        Ok(parse_rocks(input)?)
+    }
+
+    fn part1(&self, lines: &Vec<Line>) -> Result<i64> {
+        part1(lines)
+    }
+
+    fn part2(&self, lines: &Vec<Line>) -> Result<i64> {
+        part2(lines)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    start: Point,
+    end: Point
+}
+
+const SOURCE_X: i64 = 500;
+
+/// Where falling sand tries to go next, in order: straight down, then down and to the left, then
+/// down and to the right.
+const FALLS: [(isize, isize); 3] = [(0, 1), (-1, 1), (1, 1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Cave {
+    grid: Grid<char>,
+    /// The x coordinate of the grid's left-most column.
+    min_x: i64,
+    sandiness: i64,
+}
+
+impl Cave {
+    /// Scans the rock `lines` into a cave. With a `floor`, the cave is made wide enough that sand
+    /// piling up from the source can never spill over the sides.
+    fn new(lines: &[Line], floor: bool) -> Result<Self> {
+        let max_y = lines
+            .iter()
+            .map(|line| line.start.y.max(line.end.y))
+            .max().ok_or(anyhow::anyhow!("no lines"))?;
+
+        let (mut min_x, mut max_x) = lines.iter().fold((SOURCE_X, SOURCE_X), |(min_x, max_x), line| {
+            (
+                min_x.min(line.start.x).min(line.end.x),
+                max_x.max(line.start.x).max(line.end.x),
+            )
+        });
+
+        let mut height = max_y + 1;
+        if floor {
+            height = max_y + 3;
+            min_x = min_x.min(SOURCE_X - height);
+            max_x = max_x.max(SOURCE_X + height);
+        }
+
+        let mut cave = Self {
+            grid: Grid::new((max_x - min_x + 1) as usize, height as usize, '.'),
+            min_x,
+            sandiness: 0,
+        };
+
+        for line in lines {
+            for x in line.start.x.min(line.end.x)..=line.start.x.max(line.end.x) {
+                for y in line.start.y.min(line.end.y)..=line.start.y.max(line.end.y) {
+                    let position = cave.position(Point { x, y });
+                    cave.grid[position] = '#';
+                }
+            }
+        }
+
+        if floor {
+            for x in 0..cave.grid.width() {
+                cave.grid[(x, height as usize - 1)] = '#';
+            }
+        }
+
+        Ok(cave)
+    }
+
+    fn position(&self, point: Point) -> Position {
+        ((point.x - self.min_x) as usize, point.y as usize)
+    }
+
+    /// Drops one unit of sand from the source and returns whether it came to rest, rather than
+    /// falling out of the cave or finding the source already blocked.
+    fn drop_sand(&mut self) -> bool {
+        let mut sand = self.position(Point { x: SOURCE_X, y: 0 });
+        if self.grid[sand] != '.' {
+            return false;
+        }
+
+        'falling: loop {
+            for fall in FALLS {
+                match self.grid.offset(sand, fall) {
+                    None => return false,
+                    Some(next) if self.grid[next] == '.' => {
+                        sand = next;
+                        continue 'falling;
+                    },
+                    Some(_) => (),
+                }
+            }
+
+            self.grid[sand] = 'o';
+            self.sandiness += 1;
+            return true;
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "w: {:?}, h: {}, sand: {}", (self.min_x, self.min_x + self.grid.width() as i64 - 1), self.grid.height(), self.sandiness)?;
+
+        let mut grid = self.grid.clone();
+        let source = self.position(Point { x: SOURCE_X, y: 0 });
+        if grid[source] == '.' {
+            grid[source] = '+';
+        }
+        write!(f, "{grid}")
+    }
+}
+
+/// A corner of a rock path. Sand falls from `y = 0`, so rock above it would be out of the cave.
+fn rock(point: &str) -> Result<Point, ParseError> {
+    match point.parse::<Point>()? {
+        parsed if parsed.y < 0 => Err(ParseError::new("rock is above the sand source", point)),
+        parsed => Ok(parsed),
+    }
+}
+
+fn parse_rocks(input: &str) -> Result<Vec<Line>, ParseError> {
+    let paths = Records::lines(input)
+        .parse_with(|path| {
+            path.split(" -> ")
+                .map(|point| rock(point).map_err(|e| e.locate(path, point)))
+                .collect::<Result<Vec<Point>, ParseError>>()
+        })
+        .collect::<Result<Vec<Vec<Point>>, ParseError>>()?;
+
+    Ok(paths
+        .iter()
+        .flat_map(|points| points.windows(2).map(|pair| Line { start: pair[0], end: pair[1] }))
+        .collect())
+}
+
+fn part1(lines: &[Line]) -> Result<i64> {
+    let mut cave = Cave::new(lines, false)?;
+    while cave.drop_sand() {}
+    Ok(cave.sandiness)
+}
+
+fn part2(lines: &[Line]) -> Result<i64> {
+    let mut cave = Cave::new(lines, true)?;
+    while cave.drop_sand() {}
+    Ok(cave.sandiness)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day14;
+        part1 { "day14" => 24 }
+        part2 { "day14" => 93 }
+    }
+
+    #[test]
+    fn test_rock_above_source() {
+        let error = parse_rocks("498,4 -> 498,6\n503,4 -> 503,-1").unwrap_err();
+        assert_eq!((Some(2), Some(10)), (error.line, error.column));
+        assert_eq!("503,-1", error.text);
+    }
+}
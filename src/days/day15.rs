@@ -0,0 +1,170 @@
+use std::{str::FromStr, fmt::Display, collections::HashSet, ops::RangeInclusive};
+
+use anyhow::Result;
+
+use crate::{error::parse_lines, geom::Point, ParseError, Parser, RangeSet, Puzzle};
+
+/// The row to check in part 1 and the size of the area to search in part 2, which are much smaller
+/// for the example than for real inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day15 {
+    pub row: i64,
+    pub bound: i64,
+}
+
+impl Day15 {
+    pub const DEFAULT: Day15 = Day15 { row: 2000000, bound: 4000000 };
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Puzzle for Day15 {
+    type Input = Vec<Report>;
+    type Part1 = u128;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Report>> {
+        Ok(parse_reports(input)?)
+    }
+
+    fn part1(&self, reports: &Vec<Report>) -> Result<u128> {
+        part1(reports, self.row)
+    }
+
+    fn part2(&self, reports: &Vec<Report>) -> Result<i64> {
+        part2(reports, self.bound)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Report {
+    sensor: Point,
+    beacon: Point,
+}
+
+impl FromStr for Report {
+    type Err = ParseError;
+
+    // Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let point = |p: &mut Parser| {
+            let x = p.field("x=", Parser::integer)?;
+            p.literal(",")?;
+            let y = p.field("y=", Parser::integer)?;
+            Ok(Point::new(x, y))
+        };
+
+        let mut p = Parser::new(s);
+        let sensor = p.field("Sensor at", point)?;
+        let beacon = p.field(": closest beacon is at", point)?;
+        p.end()?;
+
+        Ok(Self { sensor, beacon })
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "sensor: {} beacon: {} ", self.sensor, self.beacon)?;
+        writeln!(f, "manhatten_distance: {}", self.manhattan_distance())?;
+        Ok(())
+    }
+}
+
+impl Report {
+    fn manhattan_distance(&self) -> i64 {
+        self.sensor.manhattan_distance(&self.beacon)
+    }
+
+    fn covers(&self, point: &Point) -> bool {
+        self.sensor.manhattan_distance(point) <= self.manhattan_distance()
+    }
+
+    /// The stretch of row `y` this sensor rules out, if its diamond reaches that row at all.
+    fn coverage_at(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let reach = self.manhattan_distance() - (self.sensor.y - y).abs();
+        (reach >= 0).then(|| self.sensor.x - reach..=self.sensor.x + reach)
+    }
+}
+
+const TUNING_MULTIPLIER: i64 = 4000000;
+
+fn parse_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    parse_lines(input.trim())
+}
+
+fn coverage(reports: &[Report], y: i64) -> RangeSet {
+    reports.iter().filter_map(|report| report.coverage_at(y)).collect()
+}
+
+/// The only uncovered point next to a sensor's diamond has to sit just outside the edges of
+/// several diamonds, so it lies where a `x + y` edge crosses a `x - y` edge. Checking those few
+/// crossings avoids scanning every row, though a point pinned into a corner of the search area by
+/// its bounds may still need the row scan.
+fn edge_crossings(reports: &[Report]) -> impl Iterator<Item = Point> {
+    let mut sums = Vec::new();
+    let mut differences = Vec::new();
+    for report in reports {
+        let (Point { x, y }, outside) = (report.sensor, report.manhattan_distance() + 1);
+        sums.extend([x + y - outside, x + y + outside]);
+        differences.extend([x - y - outside, x - y + outside]);
+    }
+
+    sums.into_iter()
+        .flat_map(move |sum| differences.clone().into_iter().map(move |difference| (sum, difference)))
+        .filter(|(sum, difference)| (sum - difference) % 2 == 0)
+        .map(|(sum, difference)| Point::new((sum + difference) / 2, (sum - difference) / 2))
+}
+
+fn part1(reports: &[Report], y: i64) -> Result<u128> {
+    let covered = coverage(reports, y);
+
+    let beacons = reports
+        .iter()
+        .map(|report| report.beacon)
+        .filter(|beacon| beacon.y == y && covered.contains(beacon.x))
+        .collect::<HashSet<Point>>();
+
+    Ok(covered.len() - beacons.len() as u128)
+}
+
+fn part2(reports: &[Report], bound: i64) -> Result<i64> {
+    let in_bounds = |point: &Point| (0..=bound).contains(&point.x) && (0..=bound).contains(&point.y);
+
+    let distress_beacon = edge_crossings(reports)
+        .filter(in_bounds)
+        .find(|point| !reports.iter().any(|report| report.covers(point)))
+        .or_else(|| {
+            (0..=bound).find_map(|y| {
+                let gap = coverage(reports, y).gaps(0..=bound).ranges().next().cloned()?;
+                Some(Point::new(*gap.start(), y))
+            })
+        })
+        .ok_or(anyhow::anyhow!("no uncovered point found"))?;
+
+    Ok(TUNING_MULTIPLIER * distress_beacon.x + distress_beacon.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day15 { row: 10, bound: 20 };
+        part1 { "day15" => 26 }
+        part2 { "day15" => 56000011 }
+    }
+
+    #[test]
+    fn test_coverage() {
+        let input = crate::example!("day15");
+        let reports = parse_reports(input).unwrap();
+
+        assert_eq!(vec![-2..=24], coverage(&reports, 10).ranges().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![14..=14], coverage(&reports, 11).gaps(0..=20).ranges().cloned().collect::<Vec<_>>());
+    }
+}
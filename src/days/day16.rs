@@ -0,0 +1,192 @@
+use std::{str::FromStr, collections::HashMap};
+
+use anyhow::Result;
+use pathfinding::prelude::dijkstra_all;
+
+use crate::{error::{parse, parse_lines}, ParseError, Puzzle};
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    type Input = Network;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Network> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, network: &Network) -> Result<u64> {
+        part1(network)
+    }
+
+    fn part2(&self, network: &Network) -> Result<u64> {
+        part2(network)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Valve {
+    name: String,
+    flow_rate: u64,
+    tunnels: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    // Valve BB has flow rate=13; tunnels lead to valves CC, AA
+    // Valve HH has flow rate=22; tunnel leads to valve GG
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (valve_str, tunnels_str) = s.split_once("; ")
+            .ok_or(ParseError::new("invalid valve", s))?;
+
+        let name = valve_str
+            .strip_prefix("Valve ")
+            .and_then(|rest| rest.split_whitespace().next())
+            .ok_or(ParseError::new("invalid valve name", valve_str))?
+            .to_string();
+        let (_, flow_rate) = valve_str.split_once("rate=")
+            .ok_or(ParseError::new("invalid flow rate", valve_str))?;
+
+        let tunnels = tunnels_str
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| tunnels_str.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| ParseError::new("invalid tunnels", tunnels_str).locate(s, tunnels_str))?
+            .split(", ")
+            .map(ToString::to_string)
+            .collect();
+
+        Ok(Self { name, flow_rate: parse(flow_rate).map_err(|e| e.locate(s, flow_rate))?, tunnels })
+    }
+}
+
+/// The valves worth opening, with the travel time between every pair of them. Index 0 is always
+/// the starting valve `AA`, whether or not it has any flow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    flow_rates: Vec<u64>,
+    distances: Vec<Vec<u64>>,
+}
+
+impl FromStr for Network {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let valves = parse_lines::<Valve>(s.trim())?;
+        let by_name = valves
+            .iter()
+            .map(|valve| (valve.name.as_str(), valve))
+            .collect::<HashMap<&str, &Valve>>();
+
+        let start = *by_name.get("AA").ok_or(ParseError::new("no valve AA", ""))?;
+        let mut useful = vec![start];
+        useful.extend(valves.iter().filter(|valve| valve.flow_rate > 0 && valve.name != "AA"));
+        // The search keeps the opened valves in a u64 bitmask.
+        if useful.len() - 1 > u64::BITS as usize {
+            return Err(ParseError::new(format!("too many valves with flow, at most {} are supported", u64::BITS), ""));
+        }
+
+        let mut distances = vec![vec![0; useful.len()]; useful.len()];
+        for (i, from) in useful.iter().enumerate() {
+            let reachable = dijkstra_all(&from.name.as_str(), |name| {
+                by_name
+                    .get(name)
+                    .map(|valve| valve.tunnels.iter().map(|t| (t.as_str(), 1)).collect::<Vec<_>>())
+                    .unwrap_or_default()
+            });
+
+            for (j, to) in useful.iter().enumerate() {
+                if i == j { continue; }
+                let (_, distance) = reachable.get(to.name.as_str())
+                    .ok_or_else(|| ParseError::new(format!("valve {} is unreachable from {}", to.name, from.name), ""))?;
+                distances[i][j] = *distance;
+            }
+        }
+
+        Ok(Self {
+            flow_rates: useful.iter().map(|valve| valve.flow_rate).collect(),
+            distances,
+        })
+    }
+}
+
+impl Network {
+    /// The most pressure that can be released for every set of opened valves, keyed by a bitmask
+    /// over the useful valves (excluding `AA`).
+    fn best_pressures(&self, minutes: u64) -> HashMap<u64, u64> {
+        let mut best = HashMap::new();
+        self.search(0, minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn search(&self, valve: usize, minutes_left: u64, opened: u64, released: u64, best: &mut HashMap<u64, u64>) {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = std::cmp::max(*entry, released);
+
+        for next in 1..self.flow_rates.len() {
+            let bit = 1 << (next - 1);
+            if opened & bit != 0 { continue; }
+
+            // Walk to the valve and spend a minute opening it.
+            let cost = self.distances[valve][next] + 1;
+            if cost >= minutes_left { continue; }
+
+            let remaining = minutes_left - cost;
+            self.search(next, remaining, opened | bit, released + remaining * self.flow_rates[next], best);
+        }
+    }
+}
+
+fn part1(network: &Network) -> Result<u64> {
+    let best = network.best_pressures(30);
+
+    Ok(best.values().copied().max().unwrap_or(0))
+}
+
+fn part2(network: &Network) -> Result<u64> {
+    let best = network.best_pressures(26)
+        .into_iter()
+        .collect::<Vec<(u64, u64)>>();
+
+    // You and the elephant open disjoint sets of valves, so pair up the best of each.
+    let mut result = 0;
+    for (i, (mine, my_pressure)) in best.iter().enumerate() {
+        for (theirs, their_pressure) in best[i..].iter() {
+            if mine & theirs == 0 {
+                result = std::cmp::max(result, my_pressure + their_pressure);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day16;
+        part1 { "day16" => 1651 }
+        part2 { "day16" => 1707 }
+    }
+
+    #[test]
+    fn test_parse_valve() {
+        let valve = "Valve HH has flow rate=22; tunnel leads to valve GG".parse::<Valve>().unwrap();
+        assert_eq!(Valve { name: "HH".to_string(), flow_rate: 22, tunnels: vec!["GG".to_string()] }, valve);
+    }
+
+    #[test]
+    fn test_too_many_useful_valves() {
+        let network = |valves: usize| {
+            let names = (0..valves).map(|i| format!("V{i}")).collect::<Vec<_>>();
+            let mut lines = vec![format!("Valve AA has flow rate=0; tunnels lead to valves {}", names.join(", "))];
+            lines.extend((0..valves).map(|i| format!("Valve V{i} has flow rate=1; tunnel leads to valve AA")));
+            lines.join("\n").parse::<Network>()
+        };
+        assert_eq!(65, network(64).unwrap().flow_rates.len());
+        assert!(network(65).is_err());
+    }
+}
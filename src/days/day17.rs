@@ -0,0 +1,236 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{ParseError, Puzzle};
+
+pub struct Day17;
+
+impl Puzzle for Day17 {
+    type Input = Jets;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Jets> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, jets: &Jets) -> Result<u64> {
+        part1(jets)
+    }
+
+    fn part2(&self, jets: &Jets) -> Result<u64> {
+        part2(jets)
+    }
+}
+
+const WIDTH: usize = 7;
+
+// Each rock is a list of rows from the bottom up, as bitmasks over the 7 columns with the leftmost
+// column in bit 6, already shifted so the rock starts two units away from the left wall.
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Jet {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jets(Vec<Jet>);
+
+impl FromStr for Jets {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let pattern = s.trim();
+        let jets = pattern
+            .char_indices()
+            .map(|(i, ch)| match ch {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => {
+                    let text = &pattern[i..i + ch.len_utf8()];
+                    Err(ParseError::new("invalid jet", text).locate(s, text))
+                },
+            })
+            .collect::<Result<Vec<Jet>, ParseError>>()?;
+
+        if jets.is_empty() {
+            return Err(ParseError::new("no jets in input", ""));
+        }
+
+        Ok(Self(jets))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Chamber {
+    rows: Vec<u8>,
+    jets: Vec<Jet>,
+    jet_idx: usize,
+    rock_idx: usize,
+    rocks_dropped: u64,
+}
+
+impl Chamber {
+    fn new(jets: Jets) -> Self {
+        Self {
+            rows: Vec::new(),
+            jets: jets.0,
+            jet_idx: 0,
+            rock_idx: 0,
+            rocks_dropped: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn collides(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(dy, row)| self.rows.get(y + dy).is_some_and(|settled| settled & row != 0))
+    }
+
+    fn push(rock: &[u8], jet: Jet) -> Option<Vec<u8>> {
+        match jet {
+            Jet::Left if rock.iter().all(|row| row & 0b1000000 == 0) => {
+                Some(rock.iter().map(|row| row << 1).collect())
+            },
+            Jet::Right if rock.iter().all(|row| row & 0b0000001 == 0) => {
+                Some(rock.iter().map(|row| row >> 1).collect())
+            },
+            _ => None,
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        let mut rock = ROCKS[self.rock_idx].to_vec();
+        self.rock_idx = (self.rock_idx + 1) % ROCKS.len();
+
+        let mut y = self.height() + 3;
+        loop {
+            let jet = self.jets[self.jet_idx];
+            self.jet_idx = (self.jet_idx + 1) % self.jets.len();
+
+            if let Some(pushed) = Self::push(&rock, jet) {
+                if !self.collides(&pushed, y) {
+                    rock = pushed;
+                }
+            }
+
+            if y == 0 || self.collides(&rock, y - 1) { break; }
+            y -= 1;
+        }
+
+        for (dy, row) in rock.iter().enumerate() {
+            if y + dy == self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[y + dy] |= row;
+        }
+        self.rocks_dropped += 1;
+    }
+
+    /// How far below the top of the tower the highest rock in each column is.
+    fn surface(&self) -> [usize; WIDTH] {
+        let mut surface = [self.height(); WIDTH];
+        for (x, depth) in surface.iter_mut().enumerate() {
+            let bit = 1 << (WIDTH - 1 - x);
+            if let Some(d) = self.rows.iter().rev().position(|row| row & bit != 0) {
+                *depth = d;
+            }
+        }
+        surface
+    }
+}
+
+impl Display for Chamber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "rocks: {}, height: {}", self.rocks_dropped, self.height())?;
+        for row in self.rows.iter().rev() {
+            write!(f, "|")?;
+            for x in 0..WIDTH {
+                if row & (1 << (WIDTH - 1 - x)) != 0 {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f, "|")?;
+        }
+        writeln!(f, "+{}+", "-".repeat(WIDTH))
+    }
+}
+
+/// Simulates `rocks` falling rocks, skipping ahead once the (rock, jet, surface) state repeats.
+fn tower_height(jets: &Jets, rocks: u64) -> u64 {
+    let mut chamber = Chamber::new(jets.clone());
+    let mut seen = HashMap::new();
+    let mut skipped_height = 0;
+
+    while chamber.rocks_dropped < rocks {
+        chamber.drop_rock();
+
+        if skipped_height > 0 { continue; }
+
+        let key = (chamber.rock_idx, chamber.jet_idx, chamber.surface());
+        let state = (chamber.rocks_dropped, chamber.height() as u64);
+        if let Some((prev_rocks, prev_height)) = seen.insert(key, state) {
+            let cycle_rocks = chamber.rocks_dropped - prev_rocks;
+            let cycle_height = chamber.height() as u64 - prev_height;
+            let cycles = (rocks - chamber.rocks_dropped) / cycle_rocks;
+
+            chamber.rocks_dropped += cycles * cycle_rocks;
+            skipped_height = cycles * cycle_height;
+        }
+    }
+
+    chamber.height() as u64 + skipped_height
+}
+
+fn part1(jets: &Jets) -> Result<u64> {
+    Ok(tower_height(jets, 2022))
+}
+
+fn part2(jets: &Jets) -> Result<u64> {
+    Ok(tower_height(jets, 1_000_000_000_000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day17;
+        part1 { "day17" => 3068 }
+        part2 { "day17" => 1514285714288_u64 }
+    }
+
+    #[test]
+    fn test_display() {
+        let mut chamber = Chamber::new(crate::example!("day17").parse().unwrap());
+        for _ in 0..3 {
+            chamber.drop_rock();
+        }
+        let expected = r###"
+rocks: 3, height: 6
+|..#....|
+|..#....|
+|####...|
+|..###..|
+|...#...|
+|..####.|
++-------+
+        "###;
+        assert_eq!(expected.trim(), chamber.to_string().trim());
+    }
+}
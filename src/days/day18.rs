@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use pathfinding::prelude::bfs_reach;
+
+use crate::{error::parse_lines, geom::Point3, ParseError, Puzzle};
+
+pub struct Day18;
+
+impl Puzzle for Day18 {
+    type Input = HashSet<Point3>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<HashSet<Point3>> {
+        Ok(parse_cubes(input)?)
+    }
+
+    fn part1(&self, cubes: &HashSet<Point3>) -> Result<usize> {
+        part1(cubes)
+    }
+
+    fn part2(&self, cubes: &HashSet<Point3>) -> Result<usize> {
+        part2(cubes)
+    }
+}
+
+fn parse_cubes(input: &str) -> Result<HashSet<Point3>, ParseError> {
+    Ok(parse_lines::<Point3>(input.trim())?.into_iter().collect())
+}
+
+fn part1(cubes: &HashSet<Point3>) -> Result<usize> {
+    let exposed = cubes
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbour| !cubes.contains(neighbour))
+        .count();
+
+    Ok(exposed)
+}
+
+fn part2(cubes: &HashSet<Point3>) -> Result<usize> {
+    let first = *cubes.iter().next().ok_or(anyhow::anyhow!("no cubes in input"))?;
+
+    // Flood fill the air around the droplet, within a box one unit larger than it on every side.
+    let (min, max) = cubes
+        .iter()
+        .fold((first, first), |(min, max), cube| (min.min(cube), max.max(cube)));
+    let min = min - Point3::new(1, 1, 1);
+    let max = max + Point3::new(1, 1, 1);
+
+    let exterior = bfs_reach(min, |air| {
+        air.neighbours()
+            .into_iter()
+            .filter(|p| p.within(&min, &max) && !cubes.contains(p))
+            .collect::<Vec<Point3>>()
+    })
+    .collect::<HashSet<Point3>>();
+
+    let exposed = cubes
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbour| exterior.contains(neighbour))
+        .count();
+
+    Ok(exposed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day18;
+        part1 { "day18-2" => 10, "day18" => 64 }
+        part2 { "day18" => 58 }
+    }
+}
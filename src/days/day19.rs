@@ -0,0 +1,229 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use crate::{error::{parse, parse_lines}, ParseError, Puzzle};
+
+pub struct Day19;
+
+impl Puzzle for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Blueprint>> {
+        Ok(parse_blueprints(input)?)
+    }
+
+    fn part1(&self, blueprints: &Vec<Blueprint>) -> Result<u64> {
+        part1(blueprints)
+    }
+
+    fn part2(&self, blueprints: &Vec<Blueprint>) -> Result<u64> {
+        part2(blueprints)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blueprint {
+    id: u64,
+    ore_robot: u64,
+    clay_robot: u64,
+    obsidian_robot: (u64, u64),
+    geode_robot: (u64, u64),
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    // Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot
+    // costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let numbers = s
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|token| !token.is_empty())
+            .map(|token| parse::<u64>(token).map_err(|e| e.locate(s, token)))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+
+        match numbers[..] {
+            [id, ore_robot, clay_robot, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] => Ok(Self {
+                id,
+                ore_robot,
+                clay_robot,
+                obsidian_robot: (obsidian_ore, obsidian_clay),
+                geode_robot: (geode_ore, geode_obsidian),
+            }),
+            _ => Err(ParseError::new("invalid blueprint", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+/// Resources and robots at some point in the search. Geodes are credited for the rest of the time
+/// as soon as a geode robot is built, so geode robots themselves are not tracked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    minutes_left: u64,
+    ore: u64,
+    clay: u64,
+    obsidian: u64,
+    geodes: u64,
+    ore_robots: u64,
+    clay_robots: u64,
+    obsidian_robots: u64,
+}
+
+impl State {
+    fn new(minutes: u64) -> Self {
+        Self {
+            minutes_left: minutes,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+        }
+    }
+
+    /// Minutes of collecting needed before `cost` of a resource can be afforded.
+    fn wait_for(cost: u64, have: u64, robots: u64) -> Option<u64> {
+        if have >= cost {
+            Some(0)
+        } else if robots == 0 {
+            None
+        } else {
+            Some((cost - have).div_ceil(robots))
+        }
+    }
+
+    /// Waits until `robot` can be afforded and builds it, or `None` if that won't happen in time.
+    fn build(&self, blueprint: &Blueprint, robot: Robot) -> Option<State> {
+        let (ore, clay, obsidian) = match robot {
+            Robot::Ore => (blueprint.ore_robot, 0, 0),
+            Robot::Clay => (blueprint.clay_robot, 0, 0),
+            Robot::Obsidian => (blueprint.obsidian_robot.0, blueprint.obsidian_robot.1, 0),
+            Robot::Geode => (blueprint.geode_robot.0, 0, blueprint.geode_robot.1),
+        };
+
+        let wait = [
+            Self::wait_for(ore, self.ore, self.ore_robots)?,
+            Self::wait_for(clay, self.clay, self.clay_robots)?,
+            Self::wait_for(obsidian, self.obsidian, self.obsidian_robots)?,
+        ].into_iter().max().unwrap_or(0);
+
+        // A robot finished with no minutes left can't collect anything.
+        let elapsed = wait + 1;
+        if elapsed >= self.minutes_left {
+            return None;
+        }
+
+        let mut next = State {
+            minutes_left: self.minutes_left - elapsed,
+            ore: self.ore + self.ore_robots * elapsed - ore,
+            clay: self.clay + self.clay_robots * elapsed - clay,
+            obsidian: self.obsidian + self.obsidian_robots * elapsed - obsidian,
+            ..*self
+        };
+        match robot {
+            Robot::Ore => next.ore_robots += 1,
+            Robot::Clay => next.clay_robots += 1,
+            Robot::Obsidian => next.obsidian_robots += 1,
+            Robot::Geode => next.geodes += next.minutes_left,
+        };
+
+        Some(next)
+    }
+
+    /// The geodes we'd end with if a geode robot could be built every remaining minute.
+    fn upper_bound(&self) -> u64 {
+        self.geodes + self.minutes_left * self.minutes_left.saturating_sub(1) / 2
+    }
+}
+
+impl Blueprint {
+    fn max_geodes(&self, minutes: u64) -> u64 {
+        let mut best = 0;
+        self.search(State::new(minutes), &mut best);
+        best
+    }
+
+    fn search(&self, state: State, best: &mut u64) {
+        *best = std::cmp::max(*best, state.geodes);
+        if state.upper_bound() <= *best {
+            return;
+        }
+
+        // Only one robot can be built per minute, so there's no point collecting more of a
+        // resource per minute than the most expensive robot costs.
+        let max_ore = [self.ore_robot, self.clay_robot, self.obsidian_robot.0, self.geode_robot.0]
+            .into_iter()
+            .max()
+            .unwrap_or(0);
+
+        for robot in [Robot::Geode, Robot::Obsidian, Robot::Clay, Robot::Ore] {
+            let worthwhile = match robot {
+                Robot::Ore => state.ore_robots < max_ore,
+                Robot::Clay => state.clay_robots < self.obsidian_robot.1,
+                Robot::Obsidian => state.obsidian_robots < self.geode_robot.1,
+                Robot::Geode => true,
+            };
+            if !worthwhile { continue; }
+
+            if let Some(next) = state.build(self, robot) {
+                self.search(next, best);
+            }
+        }
+    }
+}
+
+fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    parse_lines(input.trim())
+}
+
+fn part1(blueprints: &[Blueprint]) -> Result<u64> {
+    let quality_levels = blueprints
+        .iter()
+        .map(|blueprint| blueprint.id * blueprint.max_geodes(24))
+        .sum();
+
+    Ok(quality_levels)
+}
+
+fn part2(blueprints: &[Blueprint]) -> Result<u64> {
+    let geodes = blueprints
+        .iter()
+        .take(3)
+        .map(|blueprint| blueprint.max_geodes(32))
+        .product();
+
+    Ok(geodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day19;
+        part1 { "day19" => 33 }
+        part2 { "day19" => 56 * 62 }
+    }
+
+    #[test]
+    fn test_max_geodes() {
+        let blueprints = parse_blueprints(crate::example!("day19")).unwrap();
+        assert_eq!(9, blueprints[0].max_geodes(24));
+        assert_eq!(12, blueprints[1].max_geodes(24));
+        assert_eq!(56, blueprints[0].max_geodes(32));
+        assert_eq!(62, blueprints[1].max_geodes(32));
+    }
+}
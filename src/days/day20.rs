@@ -0,0 +1,102 @@
+use anyhow::Result;
+
+use crate::{ParseError, Puzzle, Records};
+
+pub struct Day20;
+
+impl Puzzle for Day20 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>> {
+        Ok(parse_numbers(input)?)
+    }
+
+    fn part1(&self, numbers: &Vec<i64>) -> Result<i64> {
+        part1(numbers)
+    }
+
+    fn part2(&self, numbers: &Vec<i64>) -> Result<i64> {
+        part2(numbers)
+    }
+}
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+fn parse_numbers(input: &str) -> Result<Vec<i64>, ParseError> {
+    Records::lines(input).parse::<i64>().collect()
+}
+
+/// Mixes the numbers `rounds` times. The list holds indices into `numbers` rather than values, so
+/// duplicates stay distinguishable and each round can move them in their original order.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<i64> {
+    // A single number has nowhere to move to.
+    if numbers.len() <= 1 {
+        return numbers.to_vec();
+    }
+
+    let mut order = (0..numbers.len()).collect::<Vec<usize>>();
+    let len = numbers.len() as i64;
+
+    for _ in 0..rounds {
+        for (idx, number) in numbers.iter().enumerate() {
+            let pos = order.iter().position(|&i| i == idx).unwrap();
+            order.remove(pos);
+            // With the number taken out, the list wraps every `len - 1` steps.
+            let new_pos = (pos as i64 + number).rem_euclid(len - 1) as usize;
+            order.insert(new_pos, idx);
+        }
+    }
+
+    order.into_iter().map(|i| numbers[i]).collect()
+}
+
+fn grove_coordinates(mixed: &[i64]) -> Result<i64> {
+    let zero = mixed.iter().position(|&n| n == 0)
+        .ok_or(anyhow::anyhow!("no 0 in the list"))?;
+
+    Ok([1000, 2000, 3000].iter().map(|offset| mixed[(zero + offset) % mixed.len()]).sum())
+}
+
+fn part1(numbers: &[i64]) -> Result<i64> {
+    grove_coordinates(&mix(numbers, 1))
+}
+
+fn part2(numbers: &[i64]) -> Result<i64> {
+    let numbers = numbers
+        .iter()
+        .map(|n| n * DECRYPTION_KEY)
+        .collect::<Vec<i64>>();
+    grove_coordinates(&mix(&numbers, 10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day20;
+        part1 { "day20" => 3 }
+        part2 { "day20" => 1623178306 }
+    }
+
+    fn rotate_to_zero(mut mixed: Vec<i64>) -> Vec<i64> {
+        let zero = mixed.iter().position(|&n| n == 0).unwrap();
+        mixed.rotate_left(zero);
+        mixed
+    }
+
+    #[test]
+    fn test_mix() {
+        let numbers = parse_numbers(crate::example!("day20")).unwrap();
+        assert_eq!(vec![0, 3, -2, 1, 2, -3, 4], rotate_to_zero(mix(&numbers, 1)));
+    }
+
+    #[test]
+    fn test_mix_duplicates() {
+        assert_eq!(vec![0, 1, 1, 2], rotate_to_zero(mix(&[1, 1, 0, 2], 1)));
+        assert_eq!(vec![0], mix(&[0], 1));
+        assert_eq!(Vec::<i64>::new(), mix(&[], 1));
+    }
+}
@@ -0,0 +1,175 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{error::parse, operator::Operator, ParseError, Puzzle, Records};
+
+pub struct Day21;
+
+impl Puzzle for Day21 {
+    type Input = Monkeys;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Monkeys> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, monkeys: &Monkeys) -> Result<i64> {
+        part1(monkeys)
+    }
+
+    fn part2(&self, monkeys: &Monkeys) -> Result<i64> {
+        part2(monkeys)
+    }
+}
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+impl FromStr for Job {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let tokens = s.split_whitespace().collect::<Vec<&str>>();
+        match tokens[..] {
+            [number] => Ok(Job::Number(parse(number).map_err(|e| e.locate(s, number))?)),
+            [left, op, right] => {
+                let op = op.parse().map_err(|e: ParseError| e.locate(s, op))?;
+                Ok(Job::Operation(left.to_string(), op, right.to_string()))
+            },
+            _ => Err(ParseError::new("invalid job", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkeys(HashMap<String, Job>);
+
+impl FromStr for Monkeys {
+    type Err = ParseError;
+
+    // root: pppw + sjmn
+    // dbpl: 5
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let monkeys = Records::lines(s)
+            .parse_with(|line| {
+                let (name, job) = line.split_once(": ").ok_or_else(|| ParseError::new("invalid monkey", line))?;
+                Ok((name.to_string(), job.parse::<Job>().map_err(|e| e.locate(line, job))?))
+            })
+            .collect::<Result<HashMap<String, Job>, ParseError>>()?;
+
+        Ok(Self(monkeys))
+    }
+}
+
+impl Monkeys {
+    fn job(&self, name: &str) -> Result<&Job> {
+        self.0.get(name).ok_or(anyhow::anyhow!("no monkey named {name}"))
+    }
+
+    fn evaluate(&self, name: &str) -> Result<i64> {
+        match self.job(name)? {
+            Job::Number(number) => Ok(*number),
+            Job::Operation(left, Operator::Div, right) => divide(self.evaluate(left)?, self.evaluate(right)?),
+            Job::Operation(left, op, right) => Ok(op.apply(self.evaluate(left)?, self.evaluate(right)?)),
+        }
+    }
+
+    fn depends_on_human(&self, name: &str) -> Result<bool> {
+        if name == HUMAN {
+            return Ok(true);
+        }
+
+        match self.job(name)? {
+            Job::Number(_) => Ok(false),
+            Job::Operation(left, _, right) => Ok(self.depends_on_human(left)? || self.depends_on_human(right)?),
+        }
+    }
+
+    /// Finds the number `humn` has to yell for the monkey `name` to yell `target`, by undoing each
+    /// operation on the way down to `humn`.
+    fn solve(&self, name: &str, target: i64) -> Result<i64> {
+        if name == HUMAN {
+            return Ok(target);
+        }
+
+        let (left, op, right) = match self.job(name)? {
+            Job::Operation(left, op, right) => (left, op, right),
+            Job::Number(_) => anyhow::bail!("{name} does not depend on {HUMAN}"),
+        };
+
+        if self.depends_on_human(left)? {
+            let right = self.evaluate(right)?;
+            let target = match op {
+                Operator::Add => target - right,
+                Operator::Sub => target + right,
+                Operator::Mul => divide(target, right)?,
+                Operator::Div => target * right,
+            };
+            self.solve(left, target)
+        } else {
+            let left = self.evaluate(left)?;
+            let target = match op {
+                Operator::Add => target - left,
+                Operator::Sub => left - target,
+                Operator::Mul => divide(target, left)?,
+                Operator::Div => divide(left, target)?,
+            };
+            self.solve(right, target)
+        }
+    }
+}
+
+/// Divides `x` by `y`, failing rather than rounding when `y` doesn't go into `x` exactly.
+fn divide(x: i64, y: i64) -> Result<i64> {
+    match (x.checked_div(y), x.checked_rem(y)) {
+        (Some(quotient), Some(0)) => Ok(quotient),
+        (Some(_), Some(_)) => anyhow::bail!("{x} is not divisible by {y}"),
+        _ => anyhow::bail!("cannot divide {x} by {y}"),
+    }
+}
+
+fn part1(monkeys: &Monkeys) -> Result<i64> {
+    monkeys.evaluate(ROOT)
+}
+
+fn part2(monkeys: &Monkeys) -> Result<i64> {
+    let (left, right) = match monkeys.job(ROOT)? {
+        Job::Operation(left, _, right) => (left, right),
+        Job::Number(_) => anyhow::bail!("{ROOT} has no operation to balance"),
+    };
+
+    if monkeys.depends_on_human(left)? {
+        monkeys.solve(left, monkeys.evaluate(right)?)
+    } else {
+        monkeys.solve(right, monkeys.evaluate(left)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day21;
+        part1 { "day21" => 152 }
+        part2 { "day21" => 301 }
+    }
+
+    #[test]
+    fn test_inexact_division() {
+        let monkeys = "root: aaaa + bbbb\naaaa: humn * twoo\ntwoo: 2\nbbbb: 3\nhumn: 5".parse::<Monkeys>().unwrap();
+        assert!(part2(&monkeys).is_err());
+
+        let monkeys = "root: humn / cccc\ncccc: 0\nhumn: 5".parse::<Monkeys>().unwrap();
+        assert!(part1(&monkeys).is_err());
+        assert!(part1(&"root: aaaa / bbbb\naaaa: 7\nbbbb: 2".parse::<Monkeys>().unwrap()).is_err());
+    }
+}
@@ -0,0 +1,385 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{geom::{Direction, Point3}, Grid, ParseError, Puzzle};
+
+pub struct Day22;
+
+impl Puzzle for Day22 {
+    type Input = (Map, Path);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<(Map, Path)> {
+        Ok(parse_notes(input)?)
+    }
+
+    fn part1(&self, (map, path): &(Map, Path)) -> Result<usize> {
+        part1(map, path)
+    }
+
+    fn part2(&self, (map, path): &(Map, Path)) -> Result<usize> {
+        part2(map, path)
+    }
+}
+
+/// The puzzle's score for ending up facing `direction`.
+fn facing_score(direction: Direction) -> usize {
+    match direction {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Instruction {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path(Vec<Instruction>);
+
+impl FromStr for Path {
+    type Err = ParseError;
+
+    // 10R5L5R10L4R5L5
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let path = s.trim();
+        let forward = |steps: &str| {
+            let steps = steps.parse().map_err(|e| ParseError::new(e, steps).locate(s, steps))?;
+            Ok::<_, ParseError>(Instruction::Forward(steps))
+        };
+
+        let mut instructions = Vec::new();
+        let mut start = 0;
+
+        for (i, ch) in path.char_indices() {
+            if ch.is_ascii_digit() {
+                continue;
+            }
+
+            if start < i {
+                instructions.push(forward(&path[start..i])?);
+            }
+            start = i + ch.len_utf8();
+            match ch {
+                'L' => instructions.push(Instruction::Left),
+                'R' => instructions.push(Instruction::Right),
+                _ => {
+                    let text = &path[i..start];
+                    return Err(ParseError::new("invalid instruction", text).locate(s, text));
+                },
+            }
+        }
+        if start < path.len() {
+            instructions.push(forward(&path[start..])?);
+        }
+
+        Ok(Self(instructions))
+    }
+}
+
+/// A tile of the map as `(row, column)`, the order the score counts them in.
+type Cell = (usize, usize);
+
+/// The board, with the space around the net filled in with `' '` so that it is rectangular.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    grid: Grid<char>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let lines = s.trim_start_matches('\n').lines().collect::<Vec<&str>>();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        let grid = lines
+            .iter()
+            .map(|line| {
+                let mut row = line.chars().collect::<Vec<char>>();
+                row.resize(width, ' ');
+                row
+            })
+            .collect::<Vec<Vec<char>>>();
+
+        for (row, line) in lines.iter().enumerate() {
+            if let Some((col, ch)) = line.char_indices().find(|(_, ch)| !matches!(ch, ' ' | '.' | '#')) {
+                let text = &line[col..col + ch.len_utf8()];
+                return Err(ParseError::new("invalid tile", text).at(row + 1, col + 1).locate(s, lines[0]));
+            }
+        }
+
+        Ok(Self { grid: Grid::from_rows(grid)? })
+    }
+}
+
+impl Map {
+    fn tile(&self, (row, col): Cell) -> char {
+        self.grid.get((col, row)).copied().unwrap_or(' ')
+    }
+
+    fn start(&self) -> Result<Cell> {
+        let col = self.grid
+            .rows()
+            .next()
+            .and_then(|row| row.iter().position(|ch| *ch == '.'))
+            .ok_or(anyhow::anyhow!("no open tile in the top row"))?;
+        Ok((0, col))
+    }
+
+    fn step((row, col): Cell, facing: Direction) -> Option<Cell> {
+        let delta = facing.delta::<isize>();
+        Some((row.checked_add_signed(delta.y)?, col.checked_add_signed(delta.x)?))
+    }
+
+    /// Where walking off the edge of the map leads when it wraps around to the other side of the
+    /// same row or column.
+    fn wrap_flat(&self, position: Cell, facing: Direction) -> (Cell, Direction) {
+        let back = facing.reverse();
+        let mut position = position;
+
+        while let Some(prev) = Self::step(position, back).filter(|p| self.tile(*p) != ' ') {
+            position = prev;
+        }
+
+        (position, facing)
+    }
+
+    fn walk<F>(&self, path: &Path, wrap: F) -> Result<usize>
+    where F: Fn(Cell, Direction) -> Result<(Cell, Direction)> {
+        let mut position = self.start()?;
+        let mut facing = Direction::Right;
+
+        for instruction in &path.0 {
+            match instruction {
+                Instruction::Left => facing = facing.turn_left(),
+                Instruction::Right => facing = facing.turn_right(),
+                Instruction::Forward(steps) => {
+                    for _ in 0..*steps {
+                        let (next, next_facing) = match Self::step(position, facing) {
+                            Some(next) if self.tile(next) != ' ' => (next, facing),
+                            _ => wrap(position, facing)?,
+                        };
+
+                        if self.tile(next) == '#' { break; }
+                        position = next;
+                        facing = next_facing;
+                    }
+                },
+            }
+        }
+
+        Ok(1000 * (position.0 + 1) + 4 * (position.1 + 1) + facing_score(facing))
+    }
+}
+
+/// One face of the cube: where it sits in the net, and which way its outward normal and its
+/// rightward and downward directions point once folded up in 3D.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Face {
+    origin: Cell,
+    normal: Point3,
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    fn direction(&self, facing: Direction) -> Point3 {
+        match facing {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+            Direction::Up => -self.down,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Folds the net of the map into a cube, so the edges are worked out from the shape of the net
+    /// rather than written down for a particular layout.
+    fn fold(map: &Map) -> Result<Self> {
+        let tiles = map.grid.iter().filter(|(_, ch)| **ch != ' ').count();
+        let size = (1..).find(|n| 6 * n * n >= tiles).unwrap_or(0);
+        if size == 0 || 6 * size * size != tiles {
+            anyhow::bail!("{tiles} tiles can't be folded into a cube");
+        }
+
+        let first = map.grid.row(0).iter().position(|ch| *ch != ' ').unwrap_or(0);
+        let mut faces = vec![Face {
+            origin: (0, first),
+            normal: Point3::new(0, 0, -1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        }];
+        let mut queue = VecDeque::from([faces[0]]);
+
+        // Each face next to one in the net is that face rotated a quarter turn about their edge.
+        while let Some(face) = queue.pop_front() {
+            for facing in Direction::ALL {
+                let delta = facing.delta::<isize>() * size as isize;
+                let origin = match (face.origin.0.checked_add_signed(delta.y), face.origin.1.checked_add_signed(delta.x)) {
+                    (Some(row), Some(col)) if map.tile((row, col)) != ' ' => (row, col),
+                    _ => continue,
+                };
+                if faces.iter().any(|f| f.origin == origin) { continue; }
+
+                let (normal, right, down) = match facing {
+                    Direction::Right => (face.right, -face.normal, face.down),
+                    Direction::Left => (-face.right, face.normal, face.down),
+                    Direction::Down => (face.down, face.right, -face.normal),
+                    Direction::Up => (-face.down, face.right, face.normal),
+                };
+                let next = Face { origin, normal, right, down };
+                faces.push(next);
+                queue.push_back(next);
+            }
+        }
+
+        if faces.len() != 6 {
+            anyhow::bail!("the net has {} connected faces instead of 6", faces.len());
+        }
+
+        Ok(Self { size, faces })
+    }
+
+    fn face_at(&self, (row, col): Cell) -> Result<&Face> {
+        self.faces
+            .iter()
+            .find(|f| (f.origin.0..f.origin.0 + self.size).contains(&row) && (f.origin.1..f.origin.1 + self.size).contains(&col))
+            .ok_or(anyhow::anyhow!("({row}, {col}) is not on the cube"))
+    }
+
+    fn face_with_normal(&self, normal: Point3) -> Result<&Face> {
+        self.faces
+            .iter()
+            .find(|f| f.normal == normal)
+            .ok_or(anyhow::anyhow!("no face with normal {normal}"))
+    }
+
+    /// Where walking off the edge of a face leads on the folded cube.
+    ///
+    /// Tiles are placed in 3D at their centres, in half-tile units so the coordinates stay whole,
+    /// with the cube centred on the origin. Stepping over an edge moves half a tile along the
+    /// current direction and half a tile back in along the old face's normal, and leaves us
+    /// heading against that normal.
+    fn wrap(&self, position: Cell, facing: Direction) -> Result<(Cell, Direction)> {
+        let size = self.size as i64;
+        let face = self.face_at(position)?;
+        let col = (position.1 - face.origin.1) as i64;
+        let row = (position.0 - face.origin.0) as i64;
+
+        let point = face.normal.scale(size) + face.right.scale(2 * col + 1 - size) + face.down.scale(2 * row + 1 - size);
+        let direction = face.direction(facing);
+        let point = point + direction - face.normal;
+        let direction = -face.normal;
+
+        let next = self.face_with_normal(face.direction(facing))?;
+        let col = (point.dot(&next.right) + size - 1) / 2;
+        let row = (point.dot(&next.down) + size - 1) / 2;
+        let facing = Direction::ALL
+            .into_iter()
+            .find(|f| next.direction(*f) == direction)
+            .ok_or(anyhow::anyhow!("no facing along {direction}"))?;
+
+        Ok(((next.origin.0 + row as usize, next.origin.1 + col as usize), facing))
+    }
+}
+
+fn parse_notes(input: &str) -> Result<(Map, Path), ParseError> {
+    let (map, path) = input.trim_end().split_once("\n\n")
+        .ok_or(ParseError::new("expected a map and a path separated by a blank line", ""))?;
+    Ok((
+        map.parse().map_err(|e: ParseError| e.locate(input, map))?,
+        path.parse().map_err(|e: ParseError| e.locate(input, path))?,
+    ))
+}
+
+fn part1(map: &Map, path: &Path) -> Result<usize> {
+    map.walk(path, |position, facing| Ok(map.wrap_flat(position, facing)))
+}
+
+fn part2(map: &Map, path: &Path) -> Result<usize> {
+    let cube = Cube::fold(map)?;
+    map.walk(path, |position, facing| cube.wrap(position, facing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::make_input;
+
+    crate::example_tests! {
+        Day22;
+        part1 { "day22" => 6032 }
+        part2 { "day22" => 5031 }
+    }
+
+    /// Walking off every edge of the cube and straight back again should return to where we
+    /// started, facing the opposite way.
+    fn assert_wraps_reversibly(map: &Map) {
+        let cube = Cube::fold(map).unwrap();
+        for (row, line) in map.grid.rows().enumerate() {
+            for (col, _) in line.iter().enumerate().filter(|(_, ch)| **ch != ' ') {
+                for facing in Direction::ALL {
+                    if Map::step((row, col), facing).is_some_and(|p| map.tile(p) != ' ') { continue; }
+
+                    let (next, next_facing) = cube.wrap((row, col), facing).unwrap();
+                    assert_ne!(' ', map.tile(next));
+                    let (prev, prev_facing) = cube.wrap(next, next_facing.reverse()).unwrap();
+                    assert_eq!(((row, col), facing), (prev, prev_facing.reverse()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_path() {
+        use Instruction::*;
+        let path = "10R5L5".parse::<Path>().unwrap();
+        assert_eq!(Path(vec![Forward(10), Right, Forward(5), Left, Forward(5)]), path);
+    }
+
+    #[test]
+    fn test_cube_wrap() {
+        let (map, _) = parse_notes(crate::example!("day22")).unwrap();
+        let cube = Cube::fold(&map).unwrap();
+
+        // The examples from the puzzle: A to B, and C to D.
+        assert_eq!(((8, 14), Direction::Down), cube.wrap((5, 11), Direction::Right).unwrap());
+        assert_eq!(((7, 1), Direction::Up), cube.wrap((11, 10), Direction::Down).unwrap());
+
+        assert_wraps_reversibly(&map);
+    }
+
+    #[test]
+    fn test_cube_wrap_other_net() {
+        // The layout of the real puzzle inputs, with 2x2 faces.
+        let map = make_input(
+            r###"
+  ....
+  ....
+  ..
+  ..
+....
+....
+..
+..
+            "###
+            ).parse::<Map>().unwrap();
+
+        assert_wraps_reversibly(&map);
+    }
+}
@@ -0,0 +1,194 @@
+use std::{collections::{HashMap, HashSet}, fmt::Display, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{geom::{Direction, Point}, ParseError, Puzzle};
+
+pub struct Day23;
+
+impl Puzzle for Day23 {
+    type Input = Grove;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grove> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, grove: &Grove) -> Result<usize> {
+        part1(grove)
+    }
+
+    fn part2(&self, grove: &Grove) -> Result<usize> {
+        part2(grove)
+    }
+}
+
+/// The three neighbours of `elf` that have to be empty for it to move in `direction`, with the one
+/// straight ahead first.
+fn lookahead(elf: Point, direction: Direction) -> [Point; 3] {
+    let ahead = elf.step(direction);
+    [ahead, ahead.step(direction.turn_left()), ahead.step(direction.turn_right())]
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grove {
+    elves: HashSet<Point>,
+    directions: [Direction; 4],
+    round: usize,
+}
+
+impl FromStr for Grove {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut elves = HashSet::new();
+        for (y, line) in s.trim().lines().enumerate() {
+            let line = line.trim();
+            for (x, (i, ch)) in line.char_indices().enumerate() {
+                match ch {
+                    '#' => { elves.insert(Point::new(x as i64, y as i64)); },
+                    '.' => (),
+                    _ => {
+                        let text = &line[i..i + ch.len_utf8()];
+                        return Err(ParseError::new("invalid tile", text).locate(s, text));
+                    },
+                }
+            }
+        }
+
+        Ok(Self {
+            elves,
+            directions: [Direction::Up, Direction::Down, Direction::Left, Direction::Right],
+            round: 0,
+        })
+    }
+}
+
+impl Grove {
+    fn has_neighbours(&self, elf: Point) -> bool {
+        elf.surrounding().iter().any(|p| self.elves.contains(p))
+    }
+
+    /// Runs one round and returns whether any elf moved.
+    fn run_round(&mut self) -> bool {
+        let mut proposals: HashMap<Point, Vec<Point>> = HashMap::new();
+
+        for elf in &self.elves {
+            if !self.has_neighbours(*elf) { continue; }
+
+            let proposal = self.directions
+                .iter()
+                .map(|direction| lookahead(*elf, *direction))
+                .find(|lookahead| lookahead.iter().all(|p| !self.elves.contains(p)));
+
+            if let Some([target, _, _]) = proposal {
+                proposals.entry(target).or_default().push(*elf);
+            }
+        }
+
+        let mut moved = false;
+        for (target, elves) in proposals {
+            if let [elf] = elves[..] {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+
+        self.directions.rotate_left(1);
+        self.round += 1;
+        moved
+    }
+
+    /// The corners of the smallest rectangle holding every elf, or `None` if there are no elves.
+    fn bounds(&self) -> Option<(Point, Point)> {
+        self.elves.iter().fold(None, |bounds, &elf| {
+            let (min, max) = bounds.unwrap_or((elf, elf));
+            Some((Point::new(min.x.min(elf.x), min.y.min(elf.y)), Point::new(max.x.max(elf.x), max.y.max(elf.y))))
+        })
+    }
+
+    fn empty_tiles(&self) -> usize {
+        let Some((min, max)) = self.bounds() else {
+            return 0;
+        };
+        let area = (max.x - min.x + 1) * (max.y - min.y + 1);
+        area as usize - self.elves.len()
+    }
+}
+
+impl Display for Grove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Round: {} | Elves: {}", self.round, self.elves.len())?;
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if self.elves.contains(&Point::new(x, y)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn part1(grove: &Grove) -> Result<usize> {
+    let mut grove = grove.clone();
+    for _ in 0..10 {
+        grove.run_round();
+    }
+    Ok(grove.empty_tiles())
+}
+
+fn part2(grove: &Grove) -> Result<usize> {
+    let mut grove = grove.clone();
+    while grove.run_round() {}
+    Ok(grove.round)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day23;
+        part1 { "day23" => 110 }
+        part2 { "day23" => 20 }
+    }
+
+    #[test]
+    fn test_small_example() {
+        let mut grove = ".....\n..##.\n..#..\n.....\n..##.\n.....".parse::<Grove>().unwrap();
+        for _ in 0..3 {
+            grove.run_round();
+        }
+        let expected = r###"
+Round: 3 | Elves: 5
+..#..
+....#
+#....
+....#
+.....
+..#..
+        "###;
+        assert_eq!(expected.trim(), grove.to_string().trim());
+        assert!(!grove.run_round());
+    }
+
+    #[test]
+    fn test_no_elves() -> Result<()> {
+        for input in ["....\n....", ""] {
+            let grove = input.parse::<Grove>()?;
+            assert_eq!(0, part1(&grove)?);
+            assert_eq!("Round: 0 | Elves: 0\n", grove.to_string());
+        }
+        Ok(())
+    }
+}
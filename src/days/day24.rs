@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use pathfinding::prelude::astar;
+
+use crate::{geom::Point, Grid, ParseError, Puzzle};
+
+pub struct Day24;
+
+impl Puzzle for Day24 {
+    type Input = Basin;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Basin> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(&self, basin: &Basin) -> Result<i64> {
+        part1(basin)
+    }
+
+    fn part2(&self, basin: &Basin) -> Result<i64> {
+        part2(basin)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Basin {
+    /// The interior at minute 0, with `.` for clear ground and `>v<^` for blizzards.
+    grid: Grid<char>,
+    width: i64,
+    height: i64,
+    period: i64,
+    /// Positions count from the top left of the interior, so the entrance is in row `-1` and the
+    /// exit in row `height`.
+    entrance: Point,
+    exit: Point,
+}
+
+/// The column of the single gap in a top or bottom wall, counted from just inside the left wall.
+fn gap(line: &str, width: i64) -> Result<i64, ParseError> {
+    let walled = line.len() as i64 == width + 2 && line.starts_with('#') && line.ends_with('#');
+    let mut gaps = line.char_indices().filter(|&(_, ch)| ch != '#');
+    match (walled, gaps.next(), gaps.next()) {
+        (true, Some((i, '.')), None) => Ok(i as i64 - 1),
+        _ => Err(ParseError::new("wall should be `#` with a single `.` gap", line)),
+    }
+}
+
+impl FromStr for Basin {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let lines = s.trim().lines().map(str::trim).collect::<Vec<&str>>();
+        if lines.len() < 3 {
+            return Err(ParseError::new("basin needs walls above and below", s.trim()));
+        }
+
+        let interior = &lines[1..lines.len() - 1];
+        let grid = interior
+            .iter()
+            .map(|line| {
+                let inner = line
+                    .strip_prefix('#')
+                    .and_then(|l| l.strip_suffix('#'))
+                    .ok_or_else(|| ParseError::new("row is not walled in", line).locate(s, line))?;
+                inner.char_indices()
+                    .map(|(i, ch)| match ch {
+                        '.' | '>' | 'v' | '<' | '^' => Ok(ch),
+                        _ => {
+                            let text = &inner[i..i + ch.len_utf8()];
+                            Err(ParseError::new("invalid tile", text).locate(s, text))
+                        },
+                    })
+                    .collect::<Result<Vec<char>, ParseError>>()
+            })
+            .collect::<Result<Vec<Vec<char>>, ParseError>>()?;
+        // Rows are numbered from the first inside the walls, and columns from just inside the
+        // left-hand wall.
+        let grid = Grid::from_rows(grid)
+            .map_err(|e| ParseError { column: e.column.map(|c| c + 1), ..e }.locate(s, interior[0]))?;
+
+        let width = grid.width() as i64;
+        let height = grid.height() as i64;
+        let (top, bottom) = (lines[0], lines[lines.len() - 1]);
+        let entrance = Point::new(gap(top, width).map_err(|e| e.locate(s, top))?, -1);
+        let exit = Point::new(gap(bottom, width).map_err(|e| e.locate(s, bottom))?, height);
+
+        Ok(Self { grid, width, height, period: lcm(width, height), entrance, exit })
+    }
+}
+
+impl Basin {
+    fn tile(&self, x: i64, y: i64) -> char {
+        self.grid[(x as usize, y as usize)]
+    }
+
+    /// Whether any blizzard covers `(x, y)` at `minute`, found by tracing each of the four kinds
+    /// of blizzard back to where it would have started.
+    fn is_blizzard(&self, Point { x, y }: Point, minute: i64) -> bool {
+        self.tile((x - minute).rem_euclid(self.width), y) == '>' ||
+            self.tile((x + minute).rem_euclid(self.width), y) == '<' ||
+            self.tile(x, (y - minute).rem_euclid(self.height)) == 'v' ||
+            self.tile(x, (y + minute).rem_euclid(self.height)) == '^'
+    }
+
+    fn is_open(&self, point: Point, minute: i64) -> bool {
+        if point == self.entrance || point == self.exit {
+            return true;
+        }
+
+        (0..self.width).contains(&point.x) && (0..self.height).contains(&point.y) && !self.is_blizzard(point, minute)
+    }
+
+    /// The fewest minutes to get from `start` to `goal`, leaving at `minute`. Blizzards repeat
+    /// every `period` minutes, so states only need the minute modulo the period.
+    fn crossing_time(&self, start: Point, goal: Point, minute: i64) -> Result<i64> {
+        let (_, minutes) = astar(
+            &(start, minute % self.period),
+            |&(point, minute)| {
+                let next = (minute + 1) % self.period;
+                [point].into_iter()
+                    .chain(point.neighbours())
+                    .filter(|p| self.is_open(*p, next))
+                    .map(|p| ((p, next), 1))
+                    .collect::<Vec<_>>()
+            },
+            |&(point, _)| goal.manhattan_distance(&point),
+            |&(point, _)| point == goal,
+        ).ok_or(anyhow::anyhow!("no way from {start} to {goal}"))?;
+
+        Ok(minutes)
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    a / gcd(a, b) * b
+}
+
+fn part1(basin: &Basin) -> Result<i64> {
+    basin.crossing_time(basin.entrance, basin.exit, 0)
+}
+
+fn part2(basin: &Basin) -> Result<i64> {
+    let trips = [
+        (basin.entrance, basin.exit),
+        (basin.exit, basin.entrance),
+        (basin.entrance, basin.exit),
+    ];
+
+    let mut minute = 0;
+    for (start, goal) in trips {
+        minute += basin.crossing_time(start, goal, minute)?;
+    }
+
+    Ok(minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::example_tests! {
+        Day24;
+        part1 { "day24" => 18 }
+        part2 { "day24" => 54 }
+    }
+
+    #[test]
+    fn test_blizzards() {
+        let basin = "#.#####\n#.....#\n#>....#\n#.....#\n#...v.#\n#.....#\n#####.#".parse::<Basin>().unwrap();
+        assert_eq!(5, basin.period);
+        assert!(basin.is_blizzard(Point::new(0, 1), 0));
+        assert!(basin.is_blizzard(Point::new(1, 1), 1));
+        assert!(basin.is_blizzard(Point::new(3, 4), 1));
+        assert!(basin.is_blizzard(Point::new(3, 1), 3));
+        assert!(!basin.is_blizzard(Point::new(3, 3), 3));
+    }
+
+    #[test]
+    fn test_walls() {
+        let basin = "#.###\n#...#\n##.##".parse::<Basin>().unwrap();
+        assert_eq!((Point::new(0, -1), Point::new(1, 1)), (basin.entrance, basin.exit));
+        assert_eq!(3, part1(&basin).unwrap());
+
+        let error = "#.###\n#...#\n##..#".parse::<Basin>().unwrap_err();
+        assert_eq!((Some(3), Some(1)), (error.line, error.column));
+        assert!("#.#.#\n#...#\n##.##".parse::<Basin>().is_err());
+        assert!("#.###\n#...#\n#####".parse::<Basin>().is_err());
+        assert!("#.##\n#...#\n##.##".parse::<Basin>().is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use std::{fmt::Display, iter::Sum, ops::Add, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{error::parse_lines, ParseError, Puzzle};
+
+pub struct Day25;
+
+impl Puzzle for Day25 {
+    type Input = Vec<Snafu>;
+    type Part1 = Snafu;
+    type Part2 = &'static str;
+
+    fn parse(&self, input: &str) -> Result<Vec<Snafu>> {
+        Ok(parse_lines(input)?)
+    }
+
+    fn part1(&self, numbers: &Vec<Snafu>) -> Result<Snafu> {
+        part1(numbers)
+    }
+
+    fn part2(&self, _numbers: &Vec<Snafu>) -> Result<&'static str> {
+        Ok("day 25 has no part 2")
+    }
+}
+
+/// A number in balanced base 5, written with the digits `=` (-2), `-` (-1), `0`, `1` and `2`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Snafu(i64);
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseError::new("empty SNAFU number", s));
+        }
+        let out_of_range = || ParseError::new("SNAFU number out of range", s);
+
+        // Accumulate in i128, as a prefix can briefly overshoot i64 before a negative digit.
+        let mut value: i128 = 0;
+        for (i, ch) in s.char_indices() {
+            let digit = match ch {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => {
+                    let text = &s[i..i + ch.len_utf8()];
+                    return Err(ParseError::new("invalid SNAFU digit", text).locate(s, text));
+                },
+            };
+            value = value.checked_mul(5)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or_else(out_of_range)?;
+        }
+
+        let value = i64::try_from(value).map_err(|_| out_of_range())?;
+        Ok(Snafu(value))
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+
+        // Work in i128 so carrying past the top digit can't overflow for i64::MIN or i64::MAX.
+        let mut value = self.0 as i128;
+        let mut digits = Vec::new();
+        while value != 0 {
+            let digit = match value.rem_euclid(5) {
+                0 => '0',
+                1 => '1',
+                2 => '2',
+                3 => '=',
+                _ => '-',
+            };
+            digits.push(digit);
+            // `=` and `-` stand for 3 and 4 less five, so carry the five into the next digit.
+            value = (value + 2).div_euclid(5);
+        }
+
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        Snafu(value)
+    }
+}
+
+impl From<Snafu> for i64 {
+    fn from(snafu: Snafu) -> Self {
+        snafu.0
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        Snafu(self.0 + other.0)
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Snafu {
+        iter.fold(Snafu::default(), Add::add)
+    }
+}
+
+fn part1(numbers: &[Snafu]) -> Result<Snafu> {
+    Ok(numbers.iter().copied().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    crate::example_tests! {
+        Day25;
+        part1 { "day25" => "2=-1=0" }
+    }
+
+    #[test]
+    fn test_snafu_digits() {
+        let cases = [
+            (1, "1"),
+            (2, "2"),
+            (3, "1="),
+            (4, "1-"),
+            (5, "10"),
+            (8, "2="),
+            (10, "20"),
+            (15, "1=0"),
+            (20, "1-0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+        ];
+
+        for (value, digits) in cases {
+            assert_eq!(digits, Snafu::from(value).to_string());
+            assert_eq!(value, i64::from(digits.parse::<Snafu>().unwrap()));
+        }
+        for value in [i64::MIN, i64::MAX] {
+            assert_eq!(value, i64::from(Snafu::from(value).to_string().parse::<Snafu>().unwrap()));
+        }
+        assert!("12a".parse::<Snafu>().is_err());
+        assert!("".parse::<Snafu>().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn snafu_round_trips(value in any::<i64>()) {
+            let digits = Snafu::from(value).to_string();
+            prop_assert_eq!(value, i64::from(digits.parse::<Snafu>().unwrap()));
+        }
+
+        #[test]
+        fn snafu_adds_like_integers(a in -(1i64 << 40)..(1i64 << 40), b in -(1i64 << 40)..(1i64 << 40)) {
+            let sum = [Snafu::from(a), Snafu::from(b)].into_iter().sum::<Snafu>();
+            prop_assert_eq!(a + b, i64::from(sum));
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::{char::ParseCharError, convert::Infallible, fmt::Display, num::{ParseFloatError, ParseIntError}, str::FromStr};
+
+use crate::records::Records;
+
+/// Malformed puzzle input, pinned to where it was found. Lines and columns count from 1, and the
+/// day is filled in by [`crate::run_day`] once the error reaches it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// Which record of the input failed, as numbered by [`Records`].
+    pub record: Option<usize>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    /// The offending text, usually the token or line that could not be parsed.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Display, text: &str) -> Self {
+        Self { day: None, record: None, line: None, column: None, text: text.to_string(), message: message.to_string() }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn in_record(mut self, record: usize) -> Self {
+        self.record = Some(record);
+        self
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Places an error raised while parsing `part` within the larger `text` it was sliced from,
+    /// turning any line and column relative to `part` into ones relative to `text`. Errors from
+    /// strings that are not slices of `text` are left alone.
+    pub fn locate(mut self, text: &str, part: &str) -> Self {
+        let start = text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset > text.len() || offset + part.len() > text.len() {
+            return self;
+        }
+
+        let before = &text[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+        let inner_line = self.line.unwrap_or(1);
+        if inner_line == 1 {
+            self.column = Some(column + self.column.map_or(0, |c| c - 1));
+        }
+        self.line = Some(line + inner_line - 1);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {day}")),
+            self.record.map(|record| format!("record {record}")),
+            self.line.map(|line| format!("line {line}")),
+            self.column.map(|column| format!("column {column}")),
+        ];
+        let location = location.into_iter().flatten().collect::<Vec<String>>();
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Errors from `FromStr` implementations that can be turned into a [`ParseError`] about the text
+/// that failed to parse.
+pub trait IntoParseError {
+    fn into_parse_error(self, text: &str) -> ParseError;
+}
+
+impl IntoParseError for ParseError {
+    fn into_parse_error(self, _text: &str) -> ParseError {
+        self
+    }
+}
+
+macro_rules! impl_into_parse_error {
+    ($($error:ty),*) => {
+        $(
+            impl IntoParseError for $error {
+                fn into_parse_error(self, text: &str) -> ParseError {
+                    ParseError::new(self, text)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_parse_error!(ParseIntError, ParseFloatError, ParseCharError, Infallible);
+
+/// Parses a single token with its own `FromStr`, such as a number, keeping the token as the
+/// error's text.
+pub fn parse<T>(text: &str) -> Result<T, ParseError>
+where T: FromStr, T::Err: Display {
+    text.parse::<T>().map_err(|e| ParseError::new(e, text))
+}
+
+/// Parses every non-blank line of `input` as a `T`, locating any error at its line.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where T: FromStr, T::Err: IntoParseError {
+    Records::lines(input).parse().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Pair(i64, i64);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, ParseError> {
+            let (a, b) = s.split_once(',').ok_or(ParseError::new("expected `a,b`", s))?;
+            Ok(Pair(parse(a).map_err(|e| e.locate(s, a))?, parse(b).map_err(|e| e.locate(s, b))?))
+        }
+    }
+
+    #[test]
+    fn test_errors_are_located() {
+        let pairs = parse_lines::<Pair>("1,2\n 3,4").unwrap();
+        assert_eq!((3, 4), (pairs[1].0, pairs[1].1));
+
+        let input = "1,2\n  3,x\n5,6";
+        let error = parse_lines::<Pair>(input).unwrap_err();
+
+        assert_eq!((Some(2), Some(5)), (error.line, error.column));
+        assert_eq!("x", error.text);
+        assert_eq!(
+            "day 7, record 2, line 2, column 5: invalid digit found in string: \"x\"",
+            error.with_day(7).to_string(),
+        );
+
+        let error = parse_lines::<Pair>("1,2\n3").unwrap_err();
+        assert_eq!("record 2, line 2, column 1: expected `a,b`: \"3\"", error.to_string());
+    }
+
+    #[test]
+    fn test_locate_nested_lines() {
+        let input = "header\n\n1,2\n1,y";
+        let (_, body) = input.split_once("\n\n").unwrap();
+        let error = parse_lines::<Pair>(body).unwrap_err().locate(input, body);
+
+        assert_eq!((Some(4), Some(3)), (error.line, error.column));
+        assert_eq!("y", error.text);
+    }
+}
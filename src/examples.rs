@@ -0,0 +1,93 @@
+//! Example inputs from the puzzle descriptions, kept as fixtures under `inputs/examples/`.
+//!
+//! A day with one example stores it as `dayNN.txt`, and further examples follow as `dayNN-2.txt`,
+//! `dayNN-3.txt` and so on. Days whose examples are all alike, such as day 6, number every one of
+//! them from `dayNN-1.txt`.
+
+/// The contents of the example fixture `inputs/examples/<name>.txt`, as a `&'static str`.
+#[macro_export]
+macro_rules! example {
+    ($name:literal) => {
+        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/examples/", $name, ".txt"))
+    };
+}
+
+/// Generates `test_part1` and, optionally, `test_part2` for a [`Puzzle`](crate::Puzzle), checking
+/// each listed example fixture against its expected answer. Answers are compared as printed, so an
+/// expected answer can be anything that displays the same way as the real one. Attributes before a
+/// part, such as `#[ignore]`, are passed on to its test.
+///
+/// ```ignore
+/// example_tests! {
+///     Day09;
+///     part1 { "day09" => 13 }
+///     part2 { "day09" => 1, "day09-2" => 36 }
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    (
+        $puzzle:expr;
+        $(#[$attr1:meta])*
+        part1 { $($name1:literal => $expected1:expr),+ $(,)? }
+        $($(#[$attr2:meta])* part2 { $($name2:literal => $expected2:expr),+ $(,)? })?
+    ) => {
+        #[test]
+        $(#[$attr1])*
+        fn test_part1() {
+            $($crate::example_tests!(@check $puzzle, part1, $name1, $expected1);)+
+        }
+
+        $(
+            #[test]
+            $(#[$attr2])*
+            fn test_part2() {
+                $($crate::example_tests!(@check $puzzle, part2, $name2, $expected2);)+
+            }
+        )?
+    };
+
+    (@check $puzzle:expr, $part:ident, $name:literal, $expected:expr) => {{
+        let puzzle = $puzzle;
+        let input = $crate::Puzzle::parse(&puzzle, $crate::example!($name))
+            .unwrap_or_else(|e| panic!("could not parse {}: {e:#}", $name));
+        let answer = $crate::Puzzle::$part(&puzzle, &input)
+            .unwrap_or_else(|e| panic!("{} failed on {}: {e:#}", stringify!($part), $name));
+        let expected = $expected;
+        assert_eq!(expected.to_string().trim(), answer.to_string().trim(), "{} of {}", stringify!($part), $name);
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::Puzzle;
+
+    /// Counts the lines of its input, or the words on them.
+    struct Count;
+
+    impl Puzzle for Count {
+        type Input = Vec<String>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(&self, input: &str) -> Result<Vec<String>> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(&self, lines: &Vec<String>) -> Result<usize> {
+            Ok(lines.len())
+        }
+
+        fn part2(&self, lines: &Vec<String>) -> Result<String> {
+            Ok(lines.iter().map(|line| line.split_whitespace().count().to_string()).collect::<Vec<_>>().join("\n"))
+        }
+    }
+
+    example_tests! {
+        Count;
+        part1 { "day02" => 3, "day06-1" => 1 }
+        part2 { "day02" => "2\n2\n2", "day06-1" => 1 }
+    }
+}
@@ -0,0 +1,364 @@
+use std::{fmt::{Debug, Display}, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{error::parse, ParseError};
+
+/// A point on an integer 3D lattice, such as a voxel position.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours(&self) -> [Point3; 6] {
+        [
+            *self + Point3::new(1, 0, 0),
+            *self - Point3::new(1, 0, 0),
+            *self + Point3::new(0, 1, 0),
+            *self - Point3::new(0, 1, 0),
+            *self + Point3::new(0, 0, 1),
+            *self - Point3::new(0, 0, 1),
+        ]
+    }
+
+    pub fn dot(&self, other: &Point3) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn scale(&self, factor: i64) -> Point3 {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// The component-wise minimum of the two points.
+    pub fn min(&self, other: &Point3) -> Point3 {
+        Point3::new(self.x.min(other.x), self.y.min(other.y), self.z.min(other.z))
+    }
+
+    /// The component-wise maximum of the two points.
+    pub fn max(&self, other: &Point3) -> Point3 {
+        Point3::new(self.x.max(other.x), self.y.max(other.y), self.z.max(other.z))
+    }
+
+    /// Whether the point lies within the box spanned by `min` and `max`, inclusive.
+    pub fn within(&self, min: &Point3, max: &Point3) -> bool {
+        (min.x..=max.x).contains(&self.x) &&
+            (min.y..=max.y).contains(&self.y) &&
+            (min.z..=max.z).contains(&self.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let coords = s.trim().split(',').map(str::trim).collect::<Vec<&str>>();
+        let [x, y, z] = coords[..] else {
+            return Err(ParseError::new("invalid point", s));
+        };
+        let coord = |c: &str| parse::<i64>(c).map_err(|e| e.locate(s, c));
+        Ok(Point3::new(coord(x)?, coord(y)?, coord(z)?))
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// A signed integer type that can be used as a [`Point`] coordinate.
+pub trait Coordinate:
+    Copy + Ord + Debug + Display + FromStr<Err = std::num::ParseIntError> +
+    Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize);
+
+/// A point on a signed integer 2D lattice. `y` grows downwards, as it does when reading a puzzle
+/// input line by line.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// The four points sharing an edge with this one, clockwise from up.
+    pub fn neighbours(&self) -> [Point<T>; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The eight points sharing an edge or a corner with this one, clockwise from up.
+    pub fn surrounding(&self) -> [Point<T>; 8] {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            (zero, -one), (one, -one), (one, zero), (one, one),
+            (zero, one), (-one, one), (-one, zero), (-one, -one),
+        ].map(|(dx, dy)| *self + Point::new(dx, dy))
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(&self, direction: Direction) -> Point<T> {
+        *self + direction.delta()
+    }
+
+    /// The component-wise sign, which turns any offset into a single step towards it.
+    pub fn signum(&self) -> Point<T> {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan_distance(&self, other: &Point<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of king moves between the points, where diagonal steps count as one.
+    pub fn chebyshev_distance(&self, other: &Point<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Coordinate> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> FromStr for Point<T> {
+    type Err = ParseError;
+
+    /// Parses either `x,y` or `x=.., y=..`, optionally wrapped in parentheses.
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let inner = s.trim();
+        let inner = inner.strip_prefix('(').and_then(|i| i.strip_suffix(')')).unwrap_or(inner);
+        let (x, y) = inner.split_once(',').ok_or(ParseError::new("invalid point", s))?;
+        let (x, y) = (x.trim(), y.trim());
+
+        let (x, y) = match (x.strip_prefix("x="), y.strip_prefix("y=")) {
+            (Some(x), Some(y)) => (x, y),
+            (None, None) => (x, y),
+            _ => return Err(ParseError::new("invalid point", s)),
+        };
+
+        let coord = |c: &str| parse::<T>(c).map_err(|e| e.locate(s, c));
+        Ok(Point::new(coord(x)?, coord(y)?))
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// One of the four orthogonal directions on a 2D grid, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Direction {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The offset of a single step this way, with up being towards negative `y`.
+    pub fn delta<T: Coordinate>(self) -> Point<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        match self {
+            Direction::Up => Point::new(zero, -one),
+            Direction::Right => Point::new(one, zero),
+            Direction::Down => Point::new(zero, one),
+            Direction::Left => Point::new(-one, zero),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s.trim() {
+            "U" | "Up" => Ok(Direction::Up),
+            "R" | "Right" => Ok(Direction::Right),
+            "D" | "Down" => Ok(Direction::Down),
+            "L" | "Left" => Ok(Direction::Left),
+            _ => Err(ParseError::new("invalid direction", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point3_from_str() -> Result<()> {
+        assert_eq!(Point3::new(2, -2, 5), "2,-2,5".parse::<Point3>()?);
+        assert!("2,2".parse::<Point3>().is_err());
+        assert!("2,2,2,2".parse::<Point3>().is_err());
+        assert!("2,a,2".parse::<Point3>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_point3_neighbours() {
+        let point = Point3::new(1, 1, 1);
+        let neighbours = point.neighbours();
+
+        assert!(neighbours.iter().all(|n| n.manhattan_distance(&point) == 1));
+        assert!(neighbours.contains(&Point3::new(1, 0, 1)));
+        assert!(neighbours.contains(&Point3::new(1, 1, 2)));
+    }
+
+    #[test]
+    fn test_point_from_str() -> Result<()> {
+        assert_eq!(Point::new(498, 4), "498,4".parse::<Point>()?);
+        assert_eq!(Point::new(-2, 15), "x=-2, y=15".parse::<Point>()?);
+        assert_eq!(Point::new(3, -1), "(3, -1)".parse::<Point<i32>>()?);
+        assert!("y=1, x=2".parse::<Point>().is_err());
+        assert!("x=1, 2".parse::<Point>().is_err());
+        assert!("1,2,3".parse::<Point>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_point_distances() {
+        let origin = Point::<i64>::default();
+        let point = Point::new(3, -4);
+
+        assert_eq!(7, origin.manhattan_distance(&point));
+        assert_eq!(4, origin.chebyshev_distance(&point));
+        assert_eq!(Point::new(1, -1), point.signum());
+        assert_eq!(Point::new(6, -8), point * 2);
+        assert_eq!(origin, point + -point);
+        assert!(origin.surrounding().iter().all(|p| origin.chebyshev_distance(p) == 1));
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            assert_eq!(Point::<i64>::default(), direction.delta() + direction.reverse().delta());
+        }
+        assert_eq!(Point::new(0, -1), Point::default().step(Direction::Up));
+        assert_eq!(Direction::Left, "L".parse::<Direction>().unwrap());
+    }
+}
@@ -0,0 +1,238 @@
+use std::{fmt::Display, ops::{Index, IndexMut}, str::FromStr};
+
+use crate::ParseError;
+
+/// A position in a [`Grid`], as `(x, y)` with `x` counting columns from the left and `y` counting
+/// rows from the top.
+pub type Position = (usize, usize);
+
+/// Offsets to the four orthogonal neighbours, clockwise from up.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to all eight surrounding neighbours, clockwise from up.
+const SURROUNDING: [(isize, isize); 8] = [
+    (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1),
+];
+
+/// A rectangular grid of cells stored row by row, such as a height map or a cave scan.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self { cells: vec![fill; width * height], width, height }
+    }
+
+    /// A grid with rows and columns swapped, so columns can be walked as rows.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Grid { cells, width: self.height, height: self.width }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, failing if they are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            let message = format!("row has {} cells, expected {width}", rows[y].len());
+            return Err(ParseError::new(message, "").at(y + 1, rows[y].len().min(width) + 1));
+        }
+
+        Ok(Self { cells: rows.into_iter().flatten().collect(), width, height })
+    }
+
+    /// Parses one row per line of `s`, converting each character with `cell`, which returns
+    /// `None` for characters that aren't valid cells. Whitespace around `s` and around each line is
+    /// trimmed, so grids whose edges can be blank have to be built with [`Grid::from_rows`].
+    pub fn parse_with<F>(s: &str, mut cell: F) -> Result<Self, ParseError>
+    where F: FnMut(char) -> Option<T> {
+        let rows = s.trim().lines()
+            .map(|line| {
+                let line = line.trim();
+                line.char_indices()
+                    .map(|(i, ch)| {
+                        let text = &line[i..i + ch.len_utf8()];
+                        cell(ch).ok_or_else(|| ParseError::new("invalid cell", text).locate(s, text))
+                    })
+                    .collect::<Result<Vec<T>, ParseError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+        Self::from_rows(rows).map_err(|e| e.locate(s, s.trim()))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position).then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        Some(&mut self.cells[position.1 * self.width + position.0])
+    }
+
+    /// The position `(dx, dy)` away from `position`, if that is still on the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The up to four on-grid positions sharing an edge with `position`.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL.iter().filter_map(move |delta| self.offset(position, *delta))
+    }
+
+    /// The up to eight on-grid positions sharing an edge or a corner with `position`.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING.iter().filter_map(move |delta| self.offset(position, *delta))
+    }
+
+    /// Every position in reading order, left to right and then top to bottom.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell alongside its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell in reading order matching `predicate`.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Position>
+    where P: FnMut(&T) -> bool {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, Some)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::make_input;
+
+    fn example_grid() -> Grid<char> {
+        make_input(
+            r###"
+            abc
+            def
+            "###
+        ).parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = example_grid();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('f', grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(Some((1, 1)), grid.find(|ch| *ch == 'e'));
+        assert_eq!("abc\ndef\n", grid.to_string());
+
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        let error = Grid::parse_with("12\n3x", |ch| ch.to_digit(10)).unwrap_err();
+        assert_eq!("line 2, column 2: invalid cell: \"x\"", error.to_string());
+        let error = "abc\nde".parse::<Grid<char>>().unwrap_err();
+        assert_eq!("line 2, column 3: row has 2 cells, expected 3", error.to_string());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours4((0, 0)).collect::<Vec<_>>());
+        assert_eq!(4, grid.neighbours4((1, 1)).count());
+        assert_eq!(3, grid.neighbours8((2, 2)).count());
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+        assert_eq!(None, grid.offset((0, 2), (0, 1)));
+    }
+
+    #[test]
+    fn test_views() {
+        let mut grid = example_grid();
+        grid[(0, 1)] = 'x';
+
+        assert_eq!(&['x', 'e', 'f'], grid.row(1));
+        assert_eq!(vec!['b', 'e'], grid.column(1).copied().collect::<Vec<char>>());
+        assert_eq!(3, grid.columns().count());
+
+        let transposed = grid.transpose();
+        assert_eq!("ax\nbe\ncf\n", transposed.to_string());
+        assert_eq!(grid, transposed.transpose());
+    }
+}
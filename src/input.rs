@@ -0,0 +1,106 @@
+use std::{io::Read, path::{Path, PathBuf}};
+
+use anyhow::{Context, Result};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayNN.txt` inside the given directory.
+    Dir(PathBuf),
+    /// A specific file, regardless of the day.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Dir(default_input_dir())
+    }
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` means stdin and anything else is a file.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the optional `[PATH|-]` argument of a single day binary.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let source = args.next()
+            .map(|arg| Self::from_arg(&arg))
+            .unwrap_or_default();
+
+        if let Some(arg) = args.next() {
+            anyhow::bail!("unexpected argument: {arg}, expected a single input path or `-` for stdin");
+        }
+
+        Ok(source)
+    }
+
+    pub fn load(&self, day: u8) -> Result<String> {
+        match self {
+            InputSource::Dir(dir) => read_file(day, &dir.join(format!("day{day:02}.txt"))),
+            InputSource::File(path) => read_file(day, path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)
+                    .with_context(|| format!("could not read input for day {day} from stdin"))?;
+                Ok(input)
+            },
+        }
+    }
+}
+
+pub fn default_input_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+}
+
+fn read_file(day: u8, path: &Path) -> Result<String> {
+    if !path.is_file() {
+        anyhow::bail!(
+            "missing input for day {day}: {} does not exist, pass a path or `-` to read stdin",
+            path.display(),
+        );
+    }
+
+    std::fs::read_to_string(path)
+        .with_context(|| format!("could not read input for day {day}: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(ToString::to_string)
+    }
+
+    #[test]
+    fn test_from_args() -> Result<()> {
+        assert_eq!(InputSource::default(), InputSource::from_args(args(""))?);
+        assert_eq!(InputSource::Stdin, InputSource::from_args(args("-"))?);
+        assert_eq!(InputSource::File("a.txt".into()), InputSource::from_args(args("a.txt"))?);
+        assert!(InputSource::from_args(args("a.txt b.txt")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_from_dir_and_file() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(dir.join("day07.txt"), "$ cd /\n")?;
+
+        assert_eq!("$ cd /\n", InputSource::Dir(dir.clone()).load(7)?);
+        assert_eq!("$ cd /\n", InputSource::File(dir.join("day07.txt")).load(3)?);
+
+        let err = InputSource::Dir(dir.clone()).load(8).unwrap_err();
+        assert!(err.to_string().starts_with("missing input for day 8:"), "{err}");
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
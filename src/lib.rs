@@ -1,21 +1,122 @@
+use std::fmt::Display;
+
 use anyhow::Result;
 
-pub fn split_input<F, T>(input: &str, delim: &str, func: F) -> Result<Vec<T>>
-    where F: Fn(&str) -> Result<T> {
-        input
-            .split(delim)
-            .map(|s| func(s))
-            .collect::<Result<Vec<T>>>()
+pub mod bench;
+pub mod days;
+pub mod error;
+mod examples;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod operator;
+pub mod parser;
+pub mod range_set;
+pub mod records;
+
+pub use error::ParseError;
+pub use grid::Grid;
+pub use input::InputSource;
+pub use parser::Parser;
+pub use range_set::RangeSet;
+pub use records::Records;
+
+/// A day's puzzle, as dispatched to by the `aoc` runner. Each part takes the raw puzzle input and
+/// returns the answer ready to be printed.
+pub trait Solution {
+    fn run_part1(&self, input: &str) -> Result<String>;
+    fn run_part2(&self, input: &str) -> Result<String>;
+    /// Times parsing and each part separately over `iterations` runs.
+    fn bench(&self, input: &str, iterations: usize) -> Result<bench::Timings>;
+}
+
+/// A day's puzzle with typed input and answers, for calling a solver from other code. The input is
+/// parsed once and can be shared by both parts. Every `Puzzle` is also a [`Solution`], which is
+/// what the runner works with.
+pub trait Puzzle {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+}
+
+impl<P: Puzzle> Solution for P {
+    fn run_part1(&self, input: &str) -> Result<String> {
+        Ok(self.part1(&self.parse(input)?)?.to_string())
+    }
+
+    fn run_part2(&self, input: &str) -> Result<String> {
+        Ok(self.part2(&self.parse(input)?)?.to_string())
+    }
+
+    fn bench(&self, input: &str, iterations: usize) -> Result<bench::Timings> {
+        bench::time(self, input, iterations)
+    }
+}
+
+pub fn run_day(day: u8, part: Option<u8>, source: &InputSource) -> Result<()> {
+    let solution = days::solution(day)
+        .ok_or_else(|| anyhow::anyhow!("no solution for day {day}"))?;
+    let input = source.load(day)?;
+    let with_day = with_day(day);
+
+    println!("Day {day:02}");
+    if part.is_none_or(|p| p == 1) {
+        println!("{}", format_answer(1, &solution.run_part1(&input).map_err(&with_day)?));
+    }
+    if part.is_none_or(|p| p == 2) {
+        println!("{}", format_answer(2, &solution.run_part2(&input).map_err(&with_day)?));
+    }
+
+    Ok(())
+}
+
+/// Labels an answer with its part. Multi-line answers, such as day 10's screen, start on a line of
+/// their own so that their rows stay aligned.
+fn format_answer(part: u8, answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("part {part}:\n{answer}")
+    } else {
+        format!("part {part}: {answer}")
+    }
+}
+
+/// Times `day` on its input over `iterations` runs.
+pub fn bench_day(day: u8, iterations: usize, source: &InputSource) -> Result<bench::Timings> {
+    let solution = days::solution(day)
+        .ok_or_else(|| anyhow::anyhow!("no solution for day {day}"))?;
+    let input = source.load(day)?;
+
+    solution.bench(&input, iterations).map_err(with_day(day))
+}
+
+/// Parse errors don't know which day they came from, so tag them on the way out.
+fn with_day(day: u8) -> impl Fn(anyhow::Error) -> anyhow::Error {
+    move |e| match e.downcast::<ParseError>() {
+        Ok(error) => error.with_day(day).into(),
+        Err(e) => e,
+    }
 }
 
+/// Strips the leading newline and the common indentation from an indented raw string literal, so
+/// examples can be written inline in tests without losing significant leading whitespace.
 pub fn make_input(input: &str) -> String {
-    input
-        .lines()
-        .skip(1)
-        // .map(|s| s.trim())
+    let lines = input.lines().skip(1).collect::<Vec<&str>>();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
         .collect::<Vec<&str>>()
         .join("\n")
-        .to_string()
 }
 
 
@@ -24,68 +125,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn split_input_chunks_on_delimiter() -> Result<()> {
-        let input = make_input(
-            r###"
-            1000
-            2000
-            3000
-
-            4000
-
-            5000
-            6000
-
-            7000
-            8000
-            9000
-
-            10000
-            "###
-        );
-
-        let expected = vec![
-            "1000\n2000\n3000",
-            "4000",
-            "5000\n6000",
-            "7000\n8000\n9000",
-            "10000\n",
-        ];
-        let result = split_input(&input, "\n\n", |s| Ok(s.to_string()))?;
-
-        assert_eq!(expected, result);
-
-        Ok(())
-    }
-
-    #[test]
-    fn split_input_with_inner_split() -> Result<()> {
-        let input = make_input(
-            r###"
-            1000
-            2000
-            3000
-
-            4000
-
-            5000
-            6000
-
-            7000
-            8000
-            9000
-
-            10000
-            "###
-        );
-        let expected = vec![6000, 4000, 11000, 24000, 10000];
-        let result = split_input(&input, "\n\n", |s| {
-            let r = split_input(s.trim(), "\n", |s| Ok(s.parse::<usize>()?))?;
-            Ok(r.iter().sum::<usize>())
-        })?;
-
-        assert_eq!(expected, result);
-
-        Ok(())
+    fn test_format_answer() {
+        assert_eq!("part 1: 13140", format_answer(1, "13140"));
+        assert_eq!("part 2:\n##..\n#..#", format_answer(2, "##..\n#..#"));
     }
 }
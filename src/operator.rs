@@ -0,0 +1,74 @@
+use std::{fmt::Display, ops::{Add, Div, Mul, Sub}, str::FromStr};
+
+use anyhow::Result;
+
+use crate::ParseError;
+
+/// A binary arithmetic operator as written in puzzle inputs, such as `old * 19` or `pppw + sjmn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    pub fn apply<T>(&self, x: T, y: T) -> T
+    where T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> {
+        match self {
+            Operator::Add => x + y,
+            Operator::Sub => x - y,
+            Operator::Mul => x * y,
+            Operator::Div => x / y,
+        }
+    }
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        match s.trim() {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Sub),
+            "*" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            _ => Err(ParseError::new("invalid operator", s)),
+        }
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_round_trip() -> Result<()> {
+        for symbol in ["+", "-", "*", "/"] {
+            assert_eq!(symbol, symbol.parse::<Operator>()?.to_string());
+        }
+        assert!("%".parse::<Operator>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_operator_apply() {
+        assert_eq!(7, Operator::Add.apply(4, 3));
+        assert_eq!(1, Operator::Sub.apply(4, 3));
+        assert_eq!(12u64, Operator::Mul.apply(4, 3));
+        assert_eq!(2, Operator::Div.apply(8, 4));
+    }
+}
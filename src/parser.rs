@@ -0,0 +1,167 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::ParseError;
+
+/// A cursor over a piece of puzzle input, for writing parsers as a sequence of the tokens they
+/// expect rather than by slicing at fixed offsets. Every step skips leading whitespace, and errors
+/// point at the text where the expected token was missing.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    text: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, rest: text }
+    }
+
+    /// The text not yet consumed.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// An error about `token`, which must be a slice of the parsed text, located within it.
+    pub fn error(&self, message: impl Display, token: &str) -> ParseError {
+        ParseError::new(message, token).locate(self.text, token)
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    /// The rest of the current line, shown as the culprit when an expected token is missing.
+    fn culprit(&self) -> &'a str {
+        self.rest.lines().next().unwrap_or("")
+    }
+
+    /// Takes the first `len` bytes of the remaining text.
+    fn take(&mut self, len: usize) -> &'a str {
+        let (token, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        token
+    }
+
+    /// Consumes exactly `literal`.
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if !self.rest.starts_with(literal) {
+            return Err(self.error(format!("expected `{literal}`"), self.culprit()));
+        }
+        self.take(literal.len());
+        Ok(())
+    }
+
+    /// Consumes `literal` if it comes next, returning whether it did.
+    pub fn optional(&mut self, literal: &str) -> bool {
+        self.literal(literal).is_ok()
+    }
+
+    /// Consumes the next run of non-whitespace characters.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let len = self.rest.find(char::is_whitespace).unwrap_or(self.rest.len());
+        if len == 0 {
+            return Err(self.error("expected a word", self.rest));
+        }
+        Ok(self.take(len))
+    }
+
+    /// Consumes an optionally signed run of digits and parses it as a `T`.
+    pub fn integer<T>(&mut self) -> Result<T, ParseError>
+    where T: FromStr, T::Err: Display {
+        self.skip_whitespace();
+        let sign = usize::from(self.rest.starts_with(['-', '+']));
+        let digits = self.rest[sign..].find(|ch: char| !ch.is_ascii_digit()).unwrap_or(self.rest.len() - sign);
+        if digits == 0 {
+            let word = { *self }.word().unwrap_or(self.rest);
+            return Err(self.error("expected an integer", word));
+        }
+
+        let token = self.take(sign + digits);
+        token.parse().map_err(|e| self.error(e, token))
+    }
+
+    /// Consumes the next word and parses it with `T`'s own `FromStr`.
+    pub fn value<T>(&mut self) -> Result<T, ParseError>
+    where T: FromStr<Err = ParseError> {
+        let word = self.word()?;
+        word.parse().map_err(|e: ParseError| e.locate(self.text, word))
+    }
+
+    /// Consumes the text up to, but not including, the next `delimiter`, or to the end if there
+    /// isn't one.
+    pub fn until(&mut self, delimiter: &str) -> &'a str {
+        self.skip_whitespace();
+        let len = self.rest.find(delimiter).unwrap_or(self.rest.len());
+        self.take(len)
+    }
+
+    /// A `label` followed by a value, as in `Test: divisible by 17`.
+    pub fn field<T, F>(&mut self, label: &str, value: F) -> Result<T, ParseError>
+    where F: FnOnce(&mut Self) -> Result<T, ParseError> {
+        self.literal(label)?;
+        value(self)
+    }
+
+    /// One or more items separated by `separator`, as in `79, 98`.
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where F: FnMut(&mut Self) -> Result<T, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.optional(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Checks that nothing but whitespace is left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if !self.rest.is_empty() {
+            return Err(self.error("unexpected trailing text", self.culprit()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let mut p = Parser::new("Starting items: 79, 98\n  Operation: new = old * 19");
+        let items = p.field("Starting items:", |p| p.separated(",", Parser::integer::<u64>)).unwrap();
+        assert_eq!(vec![79, 98], items);
+
+        p.literal("Operation: new =").unwrap();
+        assert_eq!(vec!["old", "*", "19"], [p.word().unwrap(), p.word().unwrap(), p.word().unwrap()]);
+        assert!(p.word().is_err());
+        assert!(p.end().is_ok());
+
+        let mut p = Parser::new("a -> b -> c");
+        assert_eq!("a ", p.until("->"));
+        assert!(p.optional("->"));
+        assert!(!p.optional("->"));
+        assert_eq!("b -> c", p.rest());
+    }
+
+    #[test]
+    fn test_errors_point_at_mismatch() {
+        let mut p = Parser::new("move 1 from 2\nto x");
+        assert_eq!(1, p.field("move", Parser::integer::<usize>).unwrap());
+        let error = p.field("to", Parser::integer::<usize>).unwrap_err();
+        assert_eq!("line 1, column 8: expected `to`: \"from 2\"", error.to_string());
+
+        p.literal("from 2").unwrap();
+        let error = p.field("to", Parser::integer::<usize>).unwrap_err();
+        assert_eq!("line 2, column 4: expected an integer: \"x\"", error.to_string());
+
+        let error = Parser::new("300").integer::<u8>().unwrap_err();
+        assert_eq!("line 1, column 1: number too large to fit in target type: \"300\"", error.to_string());
+
+        let mut p = Parser::new("1 2");
+        p.integer::<i64>().unwrap();
+        assert_eq!("line 1, column 3: unexpected trailing text: \"2\"", p.end().unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,205 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint inclusive ranges. Overlapping or touching ranges
+/// are merged as they are inserted, so `1..=3` and `4..=6` are kept as `1..=6`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<RangeInclusive<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer in `range`, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let (mut start, mut end) = range.into_inner();
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut placed = false;
+
+        for existing in self.ranges.drain(..) {
+            if existing.end().saturating_add(1) < start {
+                ranges.push(existing);
+            } else if end.saturating_add(1) < *existing.start() {
+                if !placed {
+                    ranges.push(start..=end);
+                    placed = true;
+                }
+                ranges.push(existing);
+            } else {
+                start = start.min(*existing.start());
+                end = end.max(*existing.end());
+            }
+        }
+
+        if !placed {
+            ranges.push(start..=end);
+        }
+        self.ranges = ranges;
+    }
+
+    /// Takes every integer in `range` out of the set, splitting any range it falls inside.
+    pub fn remove(&mut self, range: RangeInclusive<i64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Nothing is left below `i64::MIN` or above `i64::MAX`.
+        let (start, end) = range.into_inner();
+        self.ranges = self.ranges
+            .drain(..)
+            .flat_map(|existing| {
+                let (from, to) = existing.into_inner();
+                let below = start.checked_sub(1).map(|before| from..=to.min(before));
+                let above = end.checked_add(1).map(|after| from.max(after)..=to);
+                below.into_iter().chain(above)
+            })
+            .filter(|range| !range.is_empty())
+            .collect();
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intersection = RangeSet::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            intersection.insert(*a.start().max(b.start())..=*a.end().min(b.end()));
+
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        intersection
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    /// Whether every integer in `range` is in the set.
+    pub fn covers(&self, range: &RangeInclusive<i64>) -> bool {
+        range.is_empty() || self.ranges.iter().any(|r| r.start() <= range.start() && r.end() >= range.end())
+    }
+
+    /// Whether any integer in `range` is in the set.
+    pub fn overlaps(&self, range: &RangeInclusive<i64>) -> bool {
+        self.ranges.iter().any(|r| r.start() <= range.end() && range.start() <= r.end())
+    }
+
+    /// The number of integers in the set, which is one more than `u64::MAX` for the whole of `i64`.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|range| u128::from(range.end().abs_diff(*range.start())) + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The merged ranges, in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = &RangeInclusive<i64>> {
+        self.ranges.iter()
+    }
+
+    /// The ranges within `bounds` that are not in the set, in ascending order.
+    pub fn gaps(&self, bounds: RangeInclusive<i64>) -> RangeSet {
+        let mut gaps = RangeSet::from(bounds);
+        for range in self.ranges() {
+            gaps.remove(range.clone());
+        }
+        gaps
+    }
+}
+
+impl From<RangeInclusive<i64>> for RangeSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &RangeSet) -> Vec<RangeInclusive<i64>> {
+        set.ranges().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let set = [10..=12, 1..=3, 4..=5, 14..=14, 2..=2, 7..=8].into_iter().collect::<RangeSet>();
+
+        assert_eq!(vec![1..=5, 7..=8, 10..=12, 14..=14], ranges(&set));
+        assert_eq!(11, set.len());
+        assert!(set.contains(4));
+        assert!(!set.contains(6));
+        assert!(set.covers(&(10..=12)));
+        assert!(!set.covers(&(8..=10)));
+        assert!(set.overlaps(&(6..=7)));
+        assert!(!set.overlaps(&(13..=13)));
+
+        let set = [1..=3, 7..=9, 2..=8].into_iter().collect::<RangeSet>();
+        assert_eq!(vec![1..=9], ranges(&set));
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = RangeSet::from(5..=4);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = [1..=5, 10..=15].into_iter().collect::<RangeSet>();
+        let b = [4..=11, 20..=20].into_iter().collect::<RangeSet>();
+
+        assert_eq!(vec![1..=15, 20..=20], ranges(&a.union(&b)));
+        assert_eq!(vec![4..=5, 10..=11], ranges(&a.intersection(&b)));
+        assert_eq!(vec![0..=0, 6..=9, 16..=17], ranges(&a.gaps(0..=17)));
+
+        let mut c = a.clone();
+        c.remove(3..=12);
+        assert_eq!(vec![1..=2, 13..=15], ranges(&c));
+    }
+
+    #[test]
+    fn test_full_range() {
+        let mut set = RangeSet::from(i64::MIN..=i64::MAX);
+        assert_eq!(u128::from(u64::MAX) + 1, set.len());
+
+        set.remove(i64::MIN..=-1);
+        assert_eq!(vec![0..=i64::MAX], ranges(&set));
+        set.remove(10..=i64::MAX);
+        assert_eq!(vec![0..=9], ranges(&set));
+
+        let mut set = RangeSet::from(i64::MIN..=i64::MAX);
+        set.remove(i64::MIN..=i64::MAX);
+        assert!(set.is_empty());
+    }
+}
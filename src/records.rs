@@ -0,0 +1,167 @@
+use std::str::FromStr;
+
+use crate::{error::IntoParseError, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Delimiter<'a> {
+    Line,
+    BlankLine,
+    Str(&'a str),
+}
+
+/// The records in a piece of puzzle input, read lazily. Each record is trimmed and empty records
+/// are skipped, so stray blank lines and trailing newlines never reach a parser.
+///
+/// Records are slices of the input, so a nested reader over a record reports errors relative to
+/// the record, and [`Records::parse_with`] places them back in the whole input.
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+    text: &'a str,
+    rest: &'a str,
+    delimiter: Delimiter<'a>,
+}
+
+impl<'a> Records<'a> {
+    fn new(text: &'a str, delimiter: Delimiter<'a>) -> Self {
+        Self { text, rest: text, delimiter }
+    }
+
+    /// One record per line.
+    pub fn lines(text: &'a str) -> Self {
+        Self::new(text, Delimiter::Line)
+    }
+
+    /// Groups of lines separated by one or more blank lines.
+    pub fn groups(text: &'a str) -> Self {
+        Self::new(text, Delimiter::BlankLine)
+    }
+
+    /// Records separated by `delimiter`.
+    pub fn split(text: &'a str, delimiter: &'a str) -> Self {
+        Self::new(text, Delimiter::Str(delimiter))
+    }
+
+    /// The length of the next record in `rest`, and how much to skip to get past its delimiter.
+    fn next_span(&self) -> (usize, usize) {
+        match self.delimiter {
+            Delimiter::Line => match self.rest.find('\n') {
+                Some(end) => (end, end + 1),
+                None => (self.rest.len(), self.rest.len()),
+            },
+            Delimiter::BlankLine => {
+                let mut end = 0;
+                let mut started = false;
+                for line in self.rest.split_inclusive('\n') {
+                    let blank = line.trim().is_empty();
+                    if blank && started {
+                        return (end, end + line.len());
+                    }
+                    started |= !blank;
+                    end += line.len();
+                }
+                (end, end)
+            },
+            Delimiter::Str(delimiter) => match self.rest.find(delimiter) {
+                Some(end) => (end, end + delimiter.len()),
+                None => (self.rest.len(), self.rest.len()),
+            },
+        }
+    }
+
+    /// Parses each record as a `T`.
+    pub fn parse<T>(self) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+    where T: FromStr, T::Err: IntoParseError {
+        self.parse_with(|record| record.parse::<T>().map_err(|e| e.into_parse_error(record)))
+    }
+
+    /// Parses each record with `parse`, placing any error at its record in the whole input. Errors
+    /// are numbered by record, counting from 1, and an outer reader's numbering replaces that of
+    /// any reader nested inside it.
+    pub fn parse_with<T, F>(self, mut parse: F) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+    where F: FnMut(&'a str) -> Result<T, ParseError> + 'a {
+        let text = self.text;
+        self.enumerate().map(move |(idx, record)| {
+            parse(record).map_err(|e| e.locate(text, record).in_record(idx + 1))
+        })
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while !self.rest.is_empty() {
+            let (end, skip) = self.next_span();
+            let record = self.rest[..end].trim();
+            self.rest = &self.rest[skip..];
+            if !record.is_empty() {
+                return Some(record);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::make_input;
+
+    fn example_input() -> String {
+        make_input(
+            r###"
+            1000
+            2000
+            3000
+
+            4000
+
+            5000
+            6000
+
+
+            7000
+            8000
+            9000
+
+            10000
+            "###
+        ) + "\n"
+    }
+
+    #[test]
+    fn test_groups() {
+        let input = example_input();
+        let groups = Records::groups(&input).collect::<Vec<&str>>();
+
+        assert_eq!(vec!["1000\n2000\n3000", "4000", "5000\n6000", "7000\n8000\n9000", "10000"], groups);
+        assert_eq!(vec!["a", "b"], Records::split(" a ,, b,", ",").collect::<Vec<&str>>());
+        assert_eq!(vec!["x y", "z"], Records::lines("\r\n  x y \r\n\nz").collect::<Vec<&str>>());
+    }
+
+    #[test]
+    fn test_nested_records() {
+        let input = example_input();
+        let totals = Records::groups(&input)
+            .parse_with(|group| Records::lines(group).parse::<u64>().sum())
+            .collect::<Result<Vec<u64>, ParseError>>()
+            .unwrap();
+
+        assert_eq!(vec![6000, 4000, 11000, 24000, 10000], totals);
+    }
+
+    #[test]
+    fn test_failed_record() {
+        let input = example_input().replace("8000", "8o00");
+        let error = Records::groups(&input)
+            .parse_with(|group| Records::lines(group).parse::<u64>().sum::<Result<u64, ParseError>>())
+            .collect::<Result<Vec<u64>, ParseError>>()
+            .unwrap_err();
+
+        assert_eq!("record 4, line 12, column 1: invalid digit found in string: \"8o00\"", error.to_string());
+
+        let error = Records::lines("1\n\n  2x").parse::<u8>().nth(1).unwrap().unwrap_err();
+        assert_eq!((Some(2), Some(3), Some(3)), (error.record, error.line, error.column));
+        assert_eq!("2x", error.text);
+    }
+}
@@ -0,0 +1,126 @@
+use std::path::Path;
+
+use advent_of_code_2022::{days, example, input::default_input_dir};
+use anyhow::{Context, Result};
+
+/// The recorded answers for one day, as the solvers print them.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn answer(day: &str, part: &str, value: &toml::Value) -> Result<String> {
+    match value {
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Integer(n) => Ok(n.to_string()),
+        other => anyhow::bail!("{day}.{part} should be a string or an integer, got {}", other.type_str()),
+    }
+}
+
+/// Reads an answers file, indexed by day. Unknown days or parts are errors, so a typo can't quietly
+/// turn into a skipped day.
+fn parse_answers(text: &str) -> Result<Vec<Option<Answers>>> {
+    let table = text.parse::<toml::Table>().context("answers file is not valid TOML")?;
+    let mut answers = days::DAYS.map(|_| None).collect::<Vec<Option<Answers>>>();
+
+    for (key, value) in &table {
+        let idx = days::DAYS.clone()
+            .position(|day| *key == format!("day{day:02}"))
+            .with_context(|| format!("unknown day `{key}`, expected day01 to day25"))?;
+        let parts = value.as_table().with_context(|| format!("`{key}` should be a table of parts"))?;
+
+        let mut day = Answers::default();
+        for (part, value) in parts {
+            let slot = match part.as_str() {
+                "part1" => &mut day.part1,
+                "part2" => &mut day.part2,
+                other => anyhow::bail!("unknown part `{key}.{other}`, expected part1 or part2"),
+            };
+            *slot = Some(answer(key, part, value)?);
+        }
+        answers[idx] = Some(day);
+    }
+
+    Ok(answers)
+}
+
+/// Runs `day` against its input and describes every answer that differs from the recorded one.
+fn check_day(day: u8, input: &str, expected: &Answers) -> Vec<String> {
+    let solution = days::solution(day).expect("every day has a solution");
+    let parts = [
+        (1, &expected.part1, solution.run_part1(input)),
+        (2, &expected.part2, solution.run_part2(input)),
+    ];
+
+    parts.into_iter()
+        .filter_map(|(part, expected, actual)| {
+            let expected = expected.as_ref()?;
+            match actual {
+                Ok(actual) if actual.trim_end() == expected.trim_end() => None,
+                Ok(actual) => Some(format!("day {day} part {part}: expected {expected:?}, got {actual:?}")),
+                Err(e) => Some(format!("day {day} part {part}: expected {expected:?}, failed with {e:#}")),
+            }
+        })
+        .collect()
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap_or_else(|e| panic!("could not read {}: {e}", path.display()))
+}
+
+/// Checks every day with recorded answers in `dir/answers.toml` against `dir/dayNN.txt`. Returns the
+/// days that were checked and those skipped for lack of an input file, and fails on any mismatch.
+fn check_dir(dir: &Path) -> (Vec<u8>, Vec<u8>) {
+    let answers = parse_answers(&read(&dir.join("answers.toml"))).unwrap();
+
+    let (mut checked, mut skipped) = (Vec::new(), Vec::new());
+    let mut mismatches = Vec::new();
+    for (day, expected) in days::DAYS.zip(&answers) {
+        let Some(expected) = expected else { continue };
+        let path = dir.join(format!("day{day:02}.txt"));
+        if path.is_file() {
+            mismatches.extend(check_day(day, &read(&path), expected));
+            checked.push(day);
+        } else {
+            skipped.push(day);
+        }
+    }
+
+    assert!(mismatches.is_empty(), "answers differ from {}:\n{}", dir.join("answers.toml").display(), mismatches.join("\n"));
+    (checked, skipped)
+}
+
+#[test]
+fn example_inputs_match_recorded_answers() {
+    let (checked, skipped) = check_dir(&default_input_dir().join("examples"));
+    assert!(!checked.is_empty(), "no example answers recorded");
+    assert!(skipped.is_empty(), "examples missing for days {skipped:?}");
+}
+
+#[test]
+#[ignore = "needs the real inputs in inputs/ and their answers in inputs/answers.toml"]
+fn real_inputs_match_recorded_answers() {
+    let (checked, skipped) = check_dir(&default_input_dir());
+    if !skipped.is_empty() {
+        eprintln!("no input for days {skipped:?}, skipped");
+    }
+    assert!(!checked.is_empty(), "no real input has a recorded answer, nothing was checked");
+}
+
+#[test]
+fn answers_are_read_and_compared() {
+    let answers = parse_answers("[day01]\npart1 = 24000\npart2 = \"45000\"\n\n[day25]\npart1 = \"2=-1=0\"\n").unwrap();
+    assert_eq!(25, answers.len());
+    assert_eq!(Some(Answers { part1: Some("24000".into()), part2: Some("45000".into()) }), answers[0]);
+    assert_eq!(None, answers[1]);
+
+    let input = example!("day01");
+    assert!(check_day(1, input, answers[0].as_ref().unwrap()).is_empty());
+    let wrong = Answers { part1: Some("1".into()), part2: None };
+    assert_eq!(vec!["day 1 part 1: expected \"1\", got \"24000\""], check_day(1, input, &wrong));
+
+    assert!(parse_answers("[day1]\npart1 = 1\n").is_err());
+    assert!(parse_answers("[day01]\npart3 = 1\n").is_err());
+    assert!(parse_answers("[day01]\npart1 = 1.5\n").is_err());
+}
@@ -0,0 +1,22 @@
+use advent_of_code_2022::{days::{day01::Day01, day15::Day15}, example, Puzzle, Solution};
+
+#[test]
+fn puzzles_parse_once_and_solve_both_parts() {
+    let input = example!("day01");
+
+    let inventory = Day01.parse(input).unwrap();
+    assert_eq!(vec![24000, 11000, 10000, 6000, 4000], inventory);
+    assert_eq!(24000, Day01.part1(&inventory).unwrap());
+    assert_eq!(45000, Day01.part2(&inventory).unwrap());
+    assert_eq!("24000", Day01.run_part1(input).unwrap());
+}
+
+#[test]
+fn puzzles_can_be_configured() {
+    let input = example!("day15");
+
+    let day15 = Day15 { row: 10, ..Day15::default() };
+    let reports = day15.parse(input).unwrap();
+    assert_eq!(26, day15.part1(&reports).unwrap());
+    assert_ne!("26", Day15::default().run_part1(input).unwrap());
+}